@@ -1,24 +1,14 @@
 fn main() {
     use dawg::Dawg;
 
-    let mut dawgie = Dawg::new();
-
-    let mut words = vec![
+    // the words are sorted and deduplicated, and the dawg is sealed once they are all in
+    let dawgie = [
         "BAM", "BAT", "BATH", "CATH", "BATHE", "CAR", "CARS", "CAREERS", "CATH", "CRASE", "HUMAN",
         "a", "aliancia", "alpa", "aloa", "alobal",
     ]
     .iter()
-    .map(|x| x.to_string().to_uppercase())
-    .collect::<Vec<_>>();
-
-    words.sort();
-
-    for word in words {
-        dawgie.insert(word);
-    }
-
-    // seal the dawg once you're done
-    dawgie.finish();
+    .map(|x| x.to_uppercase())
+    .collect::<Dawg>();
 
     assert_eq!(dawgie.is_word(String::from("BATH"), true), Some("BATH".to_string()));
     assert!(dawgie.is_word(String::from("NOTHINGHERE"), true).is_none());
@@ -1,3 +1,29 @@
 pub mod dawg;
 pub mod search;
 pub mod tridawg;
+pub mod completion;
+pub mod grid;
+pub mod wordle;
+pub mod ladder;
+pub mod shape;
+pub mod words;
+pub mod suffix;
+pub mod rank;
+pub mod infix;
+pub mod automaton;
+pub mod compact;
+pub mod symbol;
+pub mod normalize;
+pub mod case;
+pub mod collation;
+pub mod anagram;
+pub mod gaddag;
+pub mod cursor;
+pub mod prefix;
+pub mod segment;
+pub mod compound;
+pub mod scan;
+pub mod spell;
+pub mod hunspell;
+pub mod reader;
+pub mod collect;
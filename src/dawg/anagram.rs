@@ -0,0 +1,132 @@
+use crate::dawg::dawg::Dawg;
+use crate::node::node::Node;
+
+/// A blank tile in the letters given to [`Dawg::find_anagrams`], [`Dawg::extend_with`] or [`Dawg::placements`], it stands for any letter
+pub const BLANK: &str = "?";
+
+/// The letters available to spell words with, blanks ([`BLANK`]) stand for any letter
+#[derive(Debug, Clone)]
+pub(crate) struct Rack {
+    /// the distinct letters
+    letters: Vec<String>,
+    /// how many of each letter are left
+    counts: Vec<usize>,
+    blanks: usize,
+}
+
+impl Rack {
+    pub(crate) fn new(letters: Vec<String>) -> Self {
+        let mut rack = Self { letters: vec![], counts: vec![], blanks: 0 };
+
+        for letter in letters {
+            if letter == BLANK {
+                rack.blanks += 1;
+                continue;
+            }
+
+            match rack.letters.iter().position(|known| *known == letter) {
+                Some(index) => rack.counts[index] += 1,
+                None => {
+                    rack.letters.push(letter);
+                    rack.counts.push(1);
+                }
+            }
+        }
+
+        rack
+    }
+
+    /// The number of letters (blanks included) left
+    pub(crate) fn len(&self) -> usize {
+        self.counts.iter().sum::<usize>() + self.blanks
+    }
+
+    /// Takes `letter` out of the rack, returning whether a blank had to be used for it (None when neither is left)
+    /// A blank is only used when the letter itself is not left, so that every word is spelled in a single way
+    pub(crate) fn take(&mut self, letter: &str) -> Option<bool> {
+        match self.letters.iter().position(|known| known == letter) {
+            Some(index) if self.counts[index] > 0 => {
+                self.counts[index] -= 1;
+                Some(false)
+            }
+            _ if self.blanks > 0 => {
+                self.blanks -= 1;
+                Some(true)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// A lazy iterator over the words that can be spelled with (some or all of) a set of letters, in the order of the dawg
+/// (alphabetical, or the order of its [`Collator`](crate::Collator)), every word is returned once even when letters repeat.
+/// Returned by [`Dawg::find_anagrams`] and [`Dawg::extend_with`]
+pub struct Anagrams<'a> {
+    dawg: &'a Dawg,
+    /// the nodes left to visit, with the letters of the word formed on the way to them and the letters left (the next one to visit is last)
+    stack: Vec<(Vec<String>, Node, Rack)>,
+    /// whether the words must use every letter
+    every_letter: bool,
+    /// letters the words must contain, one after the other
+    containing: Vec<String>,
+    /// the words already found with the gaddag of the dawg (see [`Dawg::build_gaddag`]), when it was used instead of a walk
+    found: Option<std::vec::IntoIter<String>>,
+}
+
+impl<'a> Anagrams<'a> {
+    pub(crate) fn new(dawg: &'a Dawg, letters: Vec<String>, every_letter: bool, containing: Vec<String>) -> Self {
+        Self {
+            dawg,
+            stack: vec![(vec![], dawg.get_root(), Rack::new(letters))],
+            every_letter,
+            containing,
+            found: None,
+        }
+    }
+
+    pub(crate) fn found(dawg: &'a Dawg, words: Vec<String>) -> Self {
+        Self { dawg, stack: vec![], every_letter: false, containing: vec![], found: Some(words.into_iter()) }
+    }
+}
+
+impl Iterator for Anagrams<'_> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(found) = &mut self.found {
+            return found.next();
+        }
+
+        while let Some((word, node, rack)) = self.stack.pop() {
+            #[cfg(not(feature = "threading"))]
+            let current = node.borrow();
+            #[cfg(feature = "threading")]
+            let current = node.lock().unwrap();
+
+            let left = rack.len();
+            // no word of the right length under this node
+            if self.every_letter && !current.reaches_length(left) {
+                continue;
+            }
+
+            // children are pushed in reverse, so that the smallest one is visited first
+            for (letter, child) in self.dawg.sorted_edges(&current).into_iter().rev() {
+                let mut rack = rack.to_owned();
+                if rack.take(&letter).is_some() {
+                    let mut word = word.to_owned();
+                    word.push(letter);
+                    self.stack.push((word, child, rack));
+                }
+            }
+
+            let complete = !self.every_letter || left == 0;
+            // the letters are matched as they were split (e.g. a tile is not matched by half of it)
+            let contains = self.containing.is_empty() || word.windows(self.containing.len()).any(|letters| letters == self.containing);
+            if current.terminal && complete && !word.is_empty() && contains {
+                return Some(word.concat());
+            }
+        }
+
+        None
+    }
+}
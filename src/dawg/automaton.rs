@@ -0,0 +1,247 @@
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use serde::{Deserialize, Serialize};
+use unicode_segmentation::UnicodeSegmentation;
+
+#[cfg(test)]
+#[path = "./automaton.test.rs"]
+mod automaton_test;
+
+/// A state of the suffix automaton, it stands for a set of substrings that end at the same positions of the text
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct State {
+    /// the length of the longest substring of this state
+    len: usize,
+    /// the state of the longest suffix that ends at more positions than this state's substrings (None for the initial state)
+    link: Option<usize>,
+    /// transitions, keyed by letter (grapheme)
+    next: HashMap<String, usize>,
+    /// the position (grapheme index) where the substrings of this state first end in the text
+    first_end: usize,
+    /// whether this state was cloned while splitting another one (clones don't add an end position of their own)
+    cloned: bool,
+}
+
+impl State {
+    fn new(len: usize, first_end: usize) -> Self {
+        Self { len, link: None, next: HashMap::new(), first_end, cloned: false }
+    }
+}
+
+/// The suffix automaton (Blumer et al. DAWG) of a text: the minimal automaton recognising every substring of the text
+/// It is built online, one letter (grapheme) at a time, in linear time and space, and answers substring queries in time proportional to the query
+///
+/// ```rust
+/// use dawg::SuffixAutomaton;
+///
+/// let automaton = SuffixAutomaton::new("abracadabra");
+///
+/// assert!(automaton.contains("cada"));
+/// assert_eq!(automaton.occurrences("abra"), 2);
+/// assert_eq!(automaton.first_occurrence("bra"), Some(1));
+/// assert_eq!(automaton.longest_repeated_substring(), Some("abra".to_string()));
+/// assert_eq!(automaton.longest_common_substring("macadamia"), "acada");
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SuffixAutomaton {
+    states: Vec<State>,
+    /// the state of the whole text
+    last: usize,
+    /// the letters of the text
+    text: Vec<String>,
+    /// the number of positions in the text where the substrings of each state end (i.e. how often they occur),
+    /// counted on the first query that needs them and forgotten whenever the text grows
+    #[serde(skip)]
+    occurrences: OnceLock<Vec<usize>>,
+}
+
+impl Default for SuffixAutomaton {
+    fn default() -> Self {
+        Self { states: vec![State::new(0, 0)], last: 0, text: vec![], occurrences: OnceLock::new() }
+    }
+}
+
+impl SuffixAutomaton {
+    /// Builds the automaton of `text`
+    pub fn new(text: impl AsRef<str>) -> Self {
+        let mut automaton = Self::default();
+        automaton.extend(text);
+        automaton
+    }
+
+    /// Appends `text` to the indexed text
+    /// The text is split into letters (graphemes) on its own, so a combining mark at the start of `text` is not joined to the previous letter
+    /// Occurrence counts are only recounted by the next query that needs them, so extending takes time linear in `text` alone
+    pub fn extend(&mut self, text: impl AsRef<str>) {
+        for letter in text.as_ref().graphemes(true) {
+            self.push(letter);
+        }
+
+        self.occurrences.take();
+    }
+
+    /// Adds a letter at the end of the text
+    fn push(&mut self, letter: &str) {
+        let position = self.text.len();
+        self.text.push(letter.to_owned());
+
+        let current = self.states.len();
+        self.states.push(State::new(self.states[self.last].len + 1, position));
+
+        let mut previous = Some(self.last);
+        while let Some(state) = previous {
+            if self.states[state].next.contains_key(letter) {
+                break;
+            }
+            self.states[state].next.insert(letter.to_owned(), current);
+            previous = self.states[state].link;
+        }
+
+        match previous {
+            None => self.states[current].link = Some(0),
+            Some(state) => {
+                let next = self.states[state].next[letter];
+
+                if self.states[state].len + 1 == self.states[next].len {
+                    self.states[current].link = Some(next);
+                } else {
+                    // `next` also stands for longer substrings that don't end here, so it is split in two
+                    let clone = self.states.len();
+                    let mut cloned = self.states[next].clone();
+                    cloned.len = self.states[state].len + 1;
+                    cloned.cloned = true;
+                    self.states.push(cloned);
+
+                    let mut previous = Some(state);
+                    while let Some(state) = previous {
+                        if self.states[state].next.get(letter) != Some(&next) {
+                            break;
+                        }
+                        self.states[state].next.insert(letter.to_owned(), clone);
+                        previous = self.states[state].link;
+                    }
+
+                    self.states[next].link = Some(clone);
+                    self.states[current].link = Some(clone);
+                }
+            }
+        }
+
+        self.last = current;
+    }
+
+    /// Every end position belongs to the state created for it and to all the states on its suffix link path,
+    /// so the counts are summed from the longest states down to the shortest ones (sorted by length with a counting sort)
+    fn occurrence_counts(&self) -> &[usize] {
+        self.occurrences.get_or_init(|| {
+            let mut by_length = vec![0; self.text.len() + 2];
+            for state in &self.states {
+                by_length[state.len + 1] += 1;
+            }
+            for length in 1..by_length.len() {
+                by_length[length] += by_length[length - 1];
+            }
+
+            let mut order = vec![0; self.states.len()];
+            for (index, state) in self.states.iter().enumerate() {
+                order[by_length[state.len]] = index;
+                by_length[state.len] += 1;
+            }
+
+            let mut counts = self.states.iter().map(|state| usize::from(!state.cloned)).collect::<Vec<_>>();
+            for state in order.into_iter().rev() {
+                if let Some(link) = self.states[state].link {
+                    counts[link] += counts[state];
+                }
+            }
+
+            counts
+        })
+    }
+
+    /// Returns the state reached by reading `pattern` from the initial state, and the number of letters in `pattern`
+    fn walk(&self, pattern: &str) -> Option<(usize, usize)> {
+        let mut state = 0;
+        let mut length = 0;
+
+        for letter in pattern.graphemes(true) {
+            state = *self.states[state].next.get(letter)?;
+            length += 1;
+        }
+
+        Some((state, length))
+    }
+
+    /// The number of letters (graphemes) in the text
+    pub fn len(&self) -> usize {
+        self.text.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// Whether `pattern` is a substring of the text
+    pub fn contains(&self, pattern: impl AsRef<str>) -> bool {
+        self.walk(pattern.as_ref()).is_some()
+    }
+
+    /// Returns the number of (possibly overlapping) occurrences of `pattern` in the text
+    /// The empty pattern occurs at every position, including the end of the text
+    pub fn occurrences(&self, pattern: impl AsRef<str>) -> usize {
+        match self.walk(pattern.as_ref()) {
+            Some((0, _)) => self.text.len() + 1,
+            Some((state, _)) => self.occurrence_counts()[state],
+            None => 0,
+        }
+    }
+
+    /// Returns the position (grapheme index) where `pattern` first occurs in the text
+    pub fn first_occurrence(&self, pattern: impl AsRef<str>) -> Option<usize> {
+        match self.walk(pattern.as_ref())? {
+            (0, _) => Some(0),
+            (state, length) => Some(self.states[state].first_end + 1 - length),
+        }
+    }
+
+    /// Returns the longest substring that occurs at least twice in the text (occurrences may overlap),
+    /// or None if no letter is repeated. When there is a tie, the one that occurs first in the text is returned
+    pub fn longest_repeated_substring(&self) -> Option<String> {
+        let counts = self.occurrence_counts();
+        let state = self.states.iter().zip(counts).skip(1)
+            .filter(|(_, occurrences)| **occurrences >= 2)
+            .map(|(state, _)| state)
+            .max_by(|a, b| a.len.cmp(&b.len).then_with(|| b.first_end.cmp(&a.first_end)))?;
+
+        Some(self.text[state.first_end + 1 - state.len..=state.first_end].concat())
+    }
+
+    /// Returns the longest string that is a substring of both the text and `other`
+    /// When there is a tie, the one that ends first in `other` is returned
+    pub fn longest_common_substring(&self, other: impl AsRef<str>) -> String {
+        let letters = other.as_ref().graphemes(true).collect::<Vec<_>>();
+
+        let (mut state, mut length) = (0, 0);
+        let (mut best_length, mut best_end) = (0, 0);
+
+        for (index, letter) in letters.iter().enumerate() {
+            while state != 0 && !self.states[state].next.contains_key(*letter) {
+                state = self.states[state].link.unwrap_or(0);
+                length = self.states[state].len;
+            }
+
+            if let Some(next) = self.states[state].next.get(*letter) {
+                state = *next;
+                length += 1;
+            }
+
+            if length > best_length {
+                best_length = length;
+                best_end = index + 1;
+            }
+        }
+
+        letters[best_end - best_length..best_end].concat()
+    }
+}
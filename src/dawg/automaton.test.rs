@@ -0,0 +1,108 @@
+#[cfg(test)]
+mod test_suffix_automaton {
+    use crate::dawg::automaton::SuffixAutomaton;
+
+    /// every substring of the text, to check the automaton against
+    fn substrings(text: &str) -> Vec<String> {
+        let chars = text.chars().collect::<Vec<_>>();
+        let mut result = vec![];
+
+        for start in 0..chars.len() {
+            for end in start + 1..=chars.len() {
+                result.push(chars[start..end].iter().collect());
+            }
+        }
+
+        result
+    }
+
+    #[test]
+    fn should_recognise_every_substring_and_nothing_else() {
+        let text = "abcbcabbca";
+        let automaton = SuffixAutomaton::new(text);
+
+        for substring in substrings(text) {
+            assert!(automaton.contains(&substring));
+            let occurrences = (0..text.len()).filter(|i| text[*i..].starts_with(&substring)).count();
+            assert_eq!(automaton.occurrences(&substring), occurrences);
+            assert_eq!(automaton.first_occurrence(&substring), text.find(&substring));
+        }
+
+        assert!(!automaton.contains("cc"));
+        assert!(!automaton.contains("abcd"));
+        assert_eq!(automaton.occurrences("cc"), 0);
+        assert_eq!(automaton.first_occurrence("cc"), None);
+        assert!(automaton.contains(""));
+        assert_eq!(automaton.occurrences(""), 11);
+    }
+
+    #[test]
+    fn should_count_overlapping_occurrences() {
+        let automaton = SuffixAutomaton::new("aaaa");
+
+        assert_eq!(automaton.occurrences("a"), 4);
+        assert_eq!(automaton.occurrences("aa"), 3);
+        assert_eq!(automaton.occurrences("aaaa"), 1);
+        assert_eq!(automaton.longest_repeated_substring(), Some("aaa".to_string()));
+    }
+
+    #[test]
+    fn should_find_the_longest_repeated_substring() {
+        assert_eq!(SuffixAutomaton::new("banana").longest_repeated_substring(), Some("ana".to_string()));
+        assert_eq!(SuffixAutomaton::new("mississippi").longest_repeated_substring(), Some("issi".to_string()));
+        assert_eq!(SuffixAutomaton::new("abcd").longest_repeated_substring(), None);
+        assert_eq!(SuffixAutomaton::new("").longest_repeated_substring(), None);
+    }
+
+    #[test]
+    fn should_find_the_longest_common_substring() {
+        let automaton = SuffixAutomaton::new("the quick brown fox");
+
+        assert_eq!(automaton.longest_common_substring("a brown dog"), " brown ");
+        assert_eq!(automaton.longest_common_substring("yyy"), "");
+        assert_eq!(automaton.longest_common_substring(""), "");
+        assert_eq!(SuffixAutomaton::new("").longest_common_substring("abc"), "");
+    }
+
+    #[test]
+    fn should_be_built_online() {
+        let mut automaton = SuffixAutomaton::new("GET /index.html 200\n");
+        assert_eq!(automaton.occurrences("200"), 1);
+
+        automaton.extend("GET /about.html 404\n");
+        automaton.extend("GET /index.html 200\n");
+
+        assert_eq!(automaton.occurrences("GET /"), 3);
+        assert_eq!(automaton.occurrences("index.html 200"), 2);
+        assert_eq!(automaton.first_occurrence("404"), Some(36));
+        assert_eq!(automaton.longest_repeated_substring(), Some("GET /index.html 200\n".to_string()));
+    }
+
+    #[test]
+    fn should_count_the_same_when_extended_one_letter_at_a_time() {
+        let text = "abcbcabbca";
+        let mut automaton = SuffixAutomaton::default();
+        for (index, letter) in text.char_indices() {
+            automaton.extend(letter.to_string());
+            assert_eq!(automaton.occurrences(letter.to_string()), text[..=index].matches(letter).count());
+        }
+
+        let whole = SuffixAutomaton::new(text);
+        for substring in substrings(text) {
+            assert_eq!(automaton.occurrences(&substring), whole.occurrences(&substring));
+        }
+        assert_eq!(automaton.longest_repeated_substring(), whole.longest_repeated_substring());
+    }
+
+    #[test]
+    fn should_work_on_graphemes() {
+        let automaton = SuffixAutomaton::new("ọmọ ọba ọmọ");
+
+        assert_eq!(automaton.len(), 11);
+        assert_eq!(automaton.occurrences("ọmọ"), 2);
+        assert_eq!(automaton.first_occurrence("ọba"), Some(4));
+        // "o" + combining dot below is a single letter, "o" alone is not in the text
+        assert!(!automaton.contains("o"));
+        assert_eq!(automaton.longest_repeated_substring(), Some("ọmọ".to_string()));
+    }
+}
@@ -0,0 +1,138 @@
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::dawg::dawg::Dawg;
+use crate::node::node::Node;
+
+#[cfg(test)]
+#[path = "./case.test.rs"]
+mod case_test;
+
+/// How letters are compared by case insensitive searches
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CaseFolding {
+    /// Full unicode case folding, letters may fold to more than one letter e.g. "ß" matches "SS" and "ss"
+    #[default]
+    Full,
+    /// Full case folding with the Turkish and Azerbaijani dotted and dotless i, i.e. "I" matches "ı" and "İ" matches "i"
+    Turkic,
+}
+
+impl CaseFolding {
+    /// Returns the folded form of `text`, two texts are the same regardless of case when their folded forms are equal
+    ///
+    /// ```rust
+    /// use dawg::CaseFolding;
+    ///
+    /// assert_eq!(CaseFolding::Full.fold("Straße"), CaseFolding::Full.fold("STRASSE"));
+    /// assert_eq!(CaseFolding::Turkic.fold("IŞIK"), CaseFolding::Turkic.fold("ışık"));
+    /// assert_ne!(CaseFolding::Full.fold("IŞIK"), CaseFolding::Full.fold("ışık"));
+    /// ```
+    pub fn fold(&self, text: &str) -> String {
+        let mut folded = String::with_capacity(text.len());
+
+        for letter in text.chars() {
+            match (self, letter) {
+                (Self::Turkic, 'I' | 'ı') => folded.push('ı'),
+                (Self::Turkic, 'İ' | 'i') => folded.push('i'),
+                // the dotless i has no case pair outside of Turkic languages
+                (Self::Full, 'ı') => folded.push('ı'),
+                // uppercasing first folds the letters that have no single lowercase form e.g. "ß" -> "SS" -> "ss"
+                _ => folded.extend(letter.to_uppercase().flat_map(char::to_lowercase)),
+            }
+        }
+
+        folded
+    }
+}
+
+impl Dawg {
+    /// Sets how letters are compared by case insensitive searches (see [`CaseFolding`])
+    ///
+    /// ```rust
+    /// use dawg::{CaseFolding, Dawg};
+    ///
+    /// let mut lexicon = Dawg::new();
+    /// lexicon.set_case_folding(CaseFolding::Turkic);
+    /// lexicon.insert("ışık".to_string());
+    /// lexicon.finish();
+    ///
+    /// assert!(lexicon.is_word("IŞIK", false).is_some());
+    /// assert!(lexicon.is_word("İŞİK", false).is_none());
+    /// ```
+    pub fn set_case_folding(&mut self, case_folding: CaseFolding) {
+        self.case_folding = case_folding;
+    }
+
+    /// Returns every node reached by reading `word` regardless of case, there can be more than one
+    /// when the lexicon has different cases of the same letters (e.g. "a" and "A"), or letters folding to many (e.g. "ß" and "SS")
+    pub(crate) fn find_all_ignoring_case(&self, word: impl AsRef<str>) -> Vec<Node> {
+        let target = self.case_folding.fold(&self.normalize(word));
+        let mut found = vec![];
+        let mut visited = HashSet::new();
+        let mut stack = vec![(self.get_root(), 0)];
+
+        while let Some((node, read)) = stack.pop() {
+            #[cfg(not(feature = "threading"))]
+            let current = node.borrow();
+            #[cfg(feature = "threading")]
+            let current = node.lock().unwrap();
+
+            if !visited.insert((current.id, read)) {
+                continue;
+            }
+
+            if read == target.len() {
+                drop(current);
+                found.push(node);
+                continue;
+            }
+
+            for (letter, child) in current.sorted_edges().into_iter().rev() {
+                let folded = self.case_folding.fold(&letter);
+
+                if target[read..].starts_with(&folded) {
+                    stack.push((child, read + folded.len()));
+                }
+            }
+        }
+
+        found
+    }
+
+    /// Returns every node reached by reading `word` from the root: at most one when the search is case sensitive, but a case insensitive
+    /// search follows every case of the letters, so it reaches one node per case variant of `word` in the lexicon (see [`Dawg::lookup`], which returns one of them)
+    ///
+    /// ```rust
+    /// use dawg::Dawg;
+    ///
+    /// let mut lexicon = Dawg::new();
+    /// for word in ["BAT", "Bath", "bats"] {
+    ///     lexicon.insert(word.to_string());
+    /// }
+    /// lexicon.finish();
+    ///
+    /// let nodes = lexicon.lookup_all("bat", false);
+    /// assert_eq!(nodes.len(), 3);
+    /// assert_eq!(lexicon.lookup_all("bat", true).len(), 1);
+    /// ```
+    pub fn lookup_all(&self, word: impl AsRef<str>, case_sensitive: bool) -> Vec<Node> {
+        match case_sensitive {
+            true => self.lookup_sequence(&self.tokenize(word)).into_iter().collect(),
+            false => self.find_all_ignoring_case(word),
+        }
+    }
+
+    /// Reads `word` regardless of case, preferring a node that ends a word when more than one is reached (see `find_all_ignoring_case`)
+    pub(crate) fn find_ignoring_case(&self, word: impl AsRef<str>) -> Option<Node> {
+        let nodes = self.find_all_ignoring_case(word);
+
+        #[cfg(not(feature = "threading"))]
+        let terminal = nodes.iter().find(|node| node.borrow().terminal);
+        #[cfg(feature = "threading")]
+        let terminal = nodes.iter().find(|node| node.lock().unwrap().terminal);
+
+        terminal.or(nodes.first()).cloned()
+    }
+}
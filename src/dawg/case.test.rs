@@ -0,0 +1,91 @@
+#[cfg(test)]
+mod test_case {
+    use crate::dawg::case::CaseFolding;
+    use crate::dawg::compact::CompactDawg;
+    use crate::dawg::dawg::Dawg;
+
+    fn setup_dawg(words: &[&str], case_folding: CaseFolding) -> Dawg {
+        let mut words = words.iter().map(|word| word.to_string()).collect::<Vec<_>>();
+        words.sort();
+
+        let mut dawg = Dawg::new();
+        dawg.set_case_folding(case_folding);
+        for word in words {
+            dawg.insert(word);
+        }
+        dawg.finish();
+        dawg
+    }
+
+    #[test]
+    fn should_fold_letters() {
+        assert_eq!(CaseFolding::Full.fold("ÀyÒ"), "àyò");
+        assert_eq!(CaseFolding::Full.fold("Straße"), "strasse");
+        assert_eq!(CaseFolding::Full.fold("ΣΊΣΥΦΟΣ"), CaseFolding::Full.fold("σίσυφος"));
+        assert_eq!(CaseFolding::Turkic.fold("DİYARBAKIR"), "diyarbakır");
+        assert_eq!(CaseFolding::Full.fold("DIYARBAKIR"), "diyarbakir");
+    }
+
+    #[test]
+    fn should_explore_every_case_of_a_letter() {
+        // "a" and "A" both start words, the word searched for is only under one of them
+        let dawg = setup_dawg(&["AB", "aC", "ac"], CaseFolding::Full);
+
+        assert!(dawg.is_word("ab", false).is_some());
+        assert!(dawg.is_word("AC", false).is_some());
+        assert!(dawg.is_word("Ac", false).is_some());
+        assert!(dawg.is_word("ad", false).is_none());
+        assert!(dawg.lookup("a", false).is_some());
+
+        // every branch is followed, not only the first one
+        let nodes = dawg.lookup_all("a", false);
+        assert_eq!(nodes.len(), 2);
+        #[cfg(not(feature = "threading"))]
+        assert_eq!(nodes.iter().map(|node| node.borrow().count).sum::<usize>(), 3);
+        #[cfg(feature = "threading")]
+        assert_eq!(nodes.iter().map(|node| node.lock().unwrap().count).sum::<usize>(), 3);
+        assert_eq!(dawg.lookup_all("a", true).len(), 1);
+        assert!(dawg.lookup_all("ad", false).is_empty());
+
+        let dawg = setup_dawg(&["A", "aB"], CaseFolding::Full);
+        // the node ending a word is preferred
+        assert!(dawg.is_word("a", false).is_some());
+        assert!(dawg.is_word("AB", false).is_some());
+    }
+
+    #[test]
+    fn should_match_letters_that_fold_to_many() {
+        let dawg = setup_dawg(&["STRAßE", "MASSE"], CaseFolding::Full);
+
+        assert!(dawg.is_word("strasse", false).is_some());
+        assert!(dawg.is_word("STRASSE", false).is_some());
+        assert!(dawg.is_word("maße", false).is_some());
+        assert!(dawg.is_word("strasse", true).is_none());
+        assert!(dawg.lookup("stras", false).is_none());
+        assert!(dawg.lookup("straß", false).is_some());
+    }
+
+    #[test]
+    fn should_fold_the_turkish_i_when_asked_to() {
+        let words = ["ışık", "istanbul"];
+
+        let dawg = setup_dawg(&words, CaseFolding::Turkic);
+        assert!(dawg.is_word("IŞIK", false).is_some());
+        assert!(dawg.is_word("İSTANBUL", false).is_some());
+        assert!(dawg.is_word("ISTANBUL", false).is_none());
+
+        let dawg = setup_dawg(&words, CaseFolding::Full);
+        assert!(dawg.is_word("ISTANBUL", false).is_some());
+        assert!(dawg.is_word("IŞIK", false).is_none());
+    }
+
+    #[test]
+    fn should_fold_letters_in_the_compact_dawg() {
+        let compact = CompactDawg::new(&setup_dawg(&["AB", "aC", "ışık"], CaseFolding::Turkic));
+
+        assert!(compact.is_word("ac", false).is_some());
+        assert!(compact.is_word("ab", false).is_some());
+        assert!(compact.is_word("IŞIK", false).is_some());
+        assert_eq!(compact.lookup("a", false), Some(2));
+    }
+}
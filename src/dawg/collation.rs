@@ -0,0 +1,217 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt::Debug;
+#[cfg(not(feature = "threading"))]
+use std::rc::Rc;
+use std::sync::{Arc, RwLock};
+
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+use crate::dawg::dawg::Dawg;
+use crate::dawg::symbol::Symbol;
+use crate::node::node::{DawgNode, Node};
+
+#[cfg(test)]
+#[path = "./collation.test.rs"]
+mod collation_test;
+
+/// Decides the order of the letters (symbols) of a dawg, and so the order words must be inserted in and are iterated in
+/// A dawg is given one with [`Dawg::set_collator`], the natural (`Ord`) order of its symbols is used otherwise.
+/// Words are compared one letter at a time (the only order a walk down the graph can produce), there is no secondary level
+/// where e.g. accents only break ties between otherwise equal words
+pub trait Collator<S: Symbol>: Debug {
+    fn compare(&self, a: &S, b: &S) -> Ordering;
+
+    /// Compares two words letter by letter, a word comes before the words it is a prefix of
+    fn compare_words(&self, a: &[S], b: &[S]) -> Ordering {
+        for (a, b) in a.iter().zip(b.iter()) {
+            match self.compare(a, b) {
+                Ordering::Equal => continue,
+                ordering => return ordering,
+            }
+        }
+
+        a.len().cmp(&b.len())
+    }
+}
+
+/// Orders letters by their position in an alphabet e.g. "Ñ" between "N" and "O" in Spanish
+/// A letter missing from the alphabet takes the position of its uppercase form, or of the letter it is made from by adding marks
+/// (so "é" sorts with "E" in French, while the Yoruba "Ẹ" has its own position), and letters still missing come after all the others.
+/// Letters at the same position are then ordered naturally, so the order of any two different letters is always decided
+///
+/// ```rust
+/// use dawg::{Alphabet, Collator};
+///
+/// let spanish = Alphabet::spanish();
+/// assert!(spanish.compare(&"Ñ".to_string(), &"O".to_string()).is_lt());
+/// assert!(spanish.compare(&"Ñ".to_string(), &"N".to_string()).is_gt());
+/// ```
+#[derive(Debug, Default)]
+pub struct Alphabet {
+    positions: HashMap<String, usize>,
+    /// the positions found for the letters missing from the alphabet, which take some unicode work to find
+    derived: RwLock<HashMap<String, Option<usize>>>,
+}
+
+impl Clone for Alphabet {
+    fn clone(&self) -> Self {
+        Self { positions: self.positions.clone(), derived: RwLock::new(self.derived.read().unwrap().clone()) }
+    }
+}
+
+impl Alphabet {
+    pub fn new<T: AsRef<str>>(letters: impl IntoIterator<Item = T>) -> Self {
+        let mut positions = HashMap::new();
+        for (position, letter) in letters.into_iter().enumerate() {
+            positions.entry(letter.as_ref().nfc().collect()).or_insert(position);
+        }
+
+        Self { positions, derived: RwLock::default() }
+    }
+
+    /// The French alphabet, accented letters sort with their base letter
+    pub fn french() -> Self {
+        Self::new("ABCDEFGHIJKLMNOPQRSTUVWXYZ".chars().map(String::from))
+    }
+
+    /// The Spanish alphabet, with "Ñ" after "N", accented vowels sort with their base letter
+    pub fn spanish() -> Self {
+        Self::new("ABCDEFGHIJKLMNÑOPQRSTUVWXYZ".chars().map(String::from))
+    }
+
+    /// The Yoruba alphabet, with "Ẹ", "Ọ", "Ṣ" and "GB" as letters of their own, tone marks sort with the letter they are on
+    /// (use [`Tiles`](crate::Tiles) to keep "GB" as a single letter)
+    pub fn yoruba() -> Self {
+        Self::new(["A", "B", "D", "E", "Ẹ", "F", "G", "GB", "H", "I", "J", "K", "L", "M", "N", "O", "Ọ", "P", "R", "S", "Ṣ", "T", "U", "W", "Y"])
+    }
+
+    /// The position of `letter`, trying its uppercase form and then removing its marks one by one until a letter of the alphabet is found
+    fn position(&self, letter: &str) -> Option<usize> {
+        if let Some(position) = self.positions.get(letter) {
+            return Some(*position);
+        }
+        if let Some(position) = self.derived.read().unwrap().get(letter) {
+            return *position;
+        }
+
+        let position = self.derive_position(letter);
+        self.derived.write().unwrap().insert(letter.to_owned(), position);
+        position
+    }
+
+    fn derive_position(&self, letter: &str) -> Option<usize> {
+        let mut letters = letter.to_uppercase().nfd().collect::<Vec<_>>();
+        loop {
+            if let Some(position) = self.positions.get(&letters.iter().copied().nfc().collect::<String>()) {
+                return Some(*position);
+            }
+
+            match letters.last() {
+                Some(last) if is_combining_mark(*last) => { letters.pop(); }
+                _ => return None,
+            }
+        }
+    }
+}
+
+impl Collator<String> for Alphabet {
+    fn compare(&self, a: &String, b: &String) -> Ordering {
+        let position = |letter| self.position(letter).unwrap_or(usize::MAX);
+        position(a).cmp(&position(b)).then_with(|| a.cmp(b))
+    }
+}
+
+impl<S: Symbol> Dawg<S> {
+    /// Sets the order of the letters of this dawg (see [`Collator`]), this should be done before inserting any word
+    pub fn set_collator(&mut self, collator: impl Collator<S> + Send + Sync + 'static) {
+        self.collator = Some(Arc::new(collator));
+    }
+
+    /// Compares two sequences with the collator of this dawg, or their natural order when it has none
+    pub(crate) fn compare_sequences(&self, a: &[S], b: &[S]) -> Ordering {
+        match &self.collator {
+            Some(collator) => collator.compare_words(a, b),
+            None => a.cmp(b),
+        }
+    }
+
+    /// Returns the edges of `node` in the order of the collator of this dawg (see [`Dawg::set_collator`]),
+    /// as stored by [`Dawg::finish`] or sorted on the spot while the dawg is being built
+    pub(crate) fn sorted_edges(&self, node: &DawgNode<S>) -> Vec<(S, Node<S>)> {
+        // edges are only ever added to a node, so an order with as many letters as it has edges is up to date
+        if node.order.len() == node.edges.len() {
+            return node.order.iter().map(|letter| {
+                #[cfg(not(feature = "threading"))]
+                return (letter.to_owned(), Rc::clone(&node.edges[letter]));
+                #[cfg(feature = "threading")]
+                return (letter.to_owned(), Arc::clone(&node.edges[letter]));
+            }).collect();
+        }
+
+        let mut edges = node.sorted_edges();
+        if let Some(collator) = &self.collator {
+            edges.sort_by(|a, b| collator.compare(&a.0, &b.0));
+        }
+        edges
+    }
+
+    /// Stores the order of the edges of `node`, and of the nodes below it, for [`Dawg::sorted_edges`]
+    pub(crate) fn order_edges(&self, node: &Node<S>) {
+        let edges = {
+            #[cfg(not(feature = "threading"))]
+            let mut current = node.borrow_mut();
+            #[cfg(feature = "threading")]
+            let mut current = node.lock().unwrap();
+
+            // a node reached by another path has already been ordered, with the nodes below it
+            if current.order.len() == current.edges.len() {
+                return;
+            }
+
+            let edges = self.sorted_edges(&current);
+            current.order = edges.iter().map(|(letter, _)| letter.to_owned()).collect();
+            edges
+        };
+
+        for (_, child) in edges {
+            self.order_edges(&child);
+        }
+    }
+}
+
+impl Dawg {
+    /// Creates a dawg whose letters are ordered by `collator` instead of their unicode code points,
+    /// words must then be inserted in that order (see [`Dawg::sort_words`]), and are iterated in it
+    ///
+    /// ```rust
+    /// use dawg::{Alphabet, Dawg};
+    ///
+    /// let mut words = vec!["ÑANDÚ".to_string(), "ZORRO".to_string(), "NUBE".to_string(), "OSO".to_string()];
+    ///
+    /// let mut lexicon = Dawg::with_collator(Alphabet::spanish());
+    /// lexicon.sort_words(&mut words);
+    /// for word in words {
+    ///     lexicon.insert(word);
+    /// }
+    /// lexicon.finish();
+    ///
+    /// assert_eq!(lexicon.words().collect::<Vec<_>>(), vec!["NUBE", "ÑANDÚ", "OSO", "ZORRO"]);
+    /// ```
+    pub fn with_collator(collator: impl Collator<String> + Send + Sync + 'static) -> Self {
+        let mut dawg = Self::default();
+        dawg.set_collator(collator);
+        dawg
+    }
+
+    /// Sorts `words` in the order they must be inserted into this dawg in, i.e. the order of its collator once they are split into letters
+    pub fn sort_words(&self, words: &mut [String]) {
+        let mut tokenized = words.iter().map(|word| (self.tokenize(word), word.to_owned())).collect::<Vec<_>>();
+        tokenized.sort_by(|a, b| self.compare_sequences(&a.0, &b.0));
+
+        for (word, (_, sorted)) in words.iter_mut().zip(tokenized) {
+            *word = sorted;
+        }
+    }
+}
@@ -0,0 +1,90 @@
+#[cfg(test)]
+mod test_collation {
+    use std::cmp::Ordering;
+
+    use crate::dawg::collation::{Alphabet, Collator};
+    use crate::dawg::compact::CompactDawg;
+    use crate::dawg::dawg::Dawg;
+
+    fn setup_dawg(words: &[&str], collator: Alphabet) -> (Dawg, Vec<String>) {
+        let mut words = words.iter().map(|word| word.to_string()).collect::<Vec<_>>();
+
+        let mut dawg = Dawg::with_collator(collator);
+        dawg.sort_words(&mut words);
+        for word in &words {
+            dawg.insert(word.to_owned());
+        }
+        dawg.finish();
+        (dawg, words)
+    }
+
+    #[test]
+    fn should_order_letters_by_their_position_in_the_alphabet() {
+        let yoruba = Alphabet::yoruba();
+        let letter = |letter: &str| letter.to_string();
+
+        assert_eq!(yoruba.compare(&letter("E"), &letter("Ẹ")), Ordering::Less);
+        assert_eq!(yoruba.compare(&letter("Ẹ"), &letter("F")), Ordering::Less);
+        // tone marks sort with the letter they are on, lowercase letters with their uppercase
+        assert_eq!(yoruba.compare(&letter("Ẹ̀"), &letter("F")), Ordering::Less);
+        assert_eq!(yoruba.compare(&letter("ọ́"), &letter("P")), Ordering::Less);
+        assert_eq!(yoruba.compare(&letter("ọ́"), &letter("O")), Ordering::Greater);
+        // letters outside of the alphabet come last
+        assert_eq!(yoruba.compare(&letter("Z"), &letter("Y")), Ordering::Greater);
+        assert_eq!(yoruba.compare(&letter("A"), &letter("A")), Ordering::Equal);
+        assert_ne!(yoruba.compare(&letter("A"), &letter("a")), Ordering::Equal);
+
+        assert_eq!(yoruba.compare_words(&[letter("A")], &[letter("A"), letter("B")]), Ordering::Less);
+        assert_eq!(yoruba.compare_words(&[letter("Ẹ")], &[letter("F"), letter("B")]), Ordering::Less);
+    }
+
+    #[test]
+    fn should_iterate_in_dictionary_order() {
+        let (dawg, words) = setup_dawg(&["ÉTÉ", "ZÈBRE", "ETE", "ÉCOLE", "FUMER", "ELLE"], Alphabet::french());
+
+        // letters are compared one at a time, so "É" comes right after "E" (rather than after "Z")
+        assert_eq!(words, vec!["ELLE", "ETE", "ÉCOLE", "ÉTÉ", "FUMER", "ZÈBRE"]);
+        assert_eq!(dawg.words().collect::<Vec<_>>(), words);
+
+        for (index, word) in words.iter().enumerate() {
+            assert_eq!(dawg.rank(word), Some(index));
+            assert_eq!(dawg.word_at(index).as_ref(), Some(word));
+        }
+
+        assert_eq!(CompactDawg::new(&dawg).words().collect::<Vec<_>>(), words);
+        assert_eq!(CompactDawg::new(&dawg).rank("ÉTÉ"), Some(3));
+    }
+
+    #[test]
+    fn should_store_the_order_of_the_edges_when_finished() {
+        let (dawg, _) = setup_dawg(&["ÉTÉ", "ZÈBRE", "ETE", "ÉCOLE", "FUMER", "ELLE"], Alphabet::french());
+
+        let root = dawg.get_root();
+        #[cfg(not(feature = "threading"))]
+        let root = root.borrow();
+        #[cfg(feature = "threading")]
+        let root = root.lock().unwrap();
+
+        assert_eq!(root.order, vec!["E", "É", "F", "Z"]);
+        let letters = dawg.sorted_edges(&root).into_iter().map(|(letter, _)| letter).collect::<Vec<_>>();
+        assert_eq!(letters, root.order);
+    }
+
+    #[test]
+    fn should_keep_extra_letters_in_place() {
+        let (dawg, words) = setup_dawg(&["ỌMỌ", "OKO", "PÁPÁ", "ẸJA", "EJÒ", "FỌ"], Alphabet::yoruba());
+
+        assert_eq!(words, vec!["EJÒ", "ẸJA", "FỌ", "OKO", "ỌMỌ", "PÁPÁ"]);
+        assert_eq!(dawg.words().collect::<Vec<_>>(), words);
+        assert_eq!(dawg.words_with_prefix("F").collect::<Vec<_>>(), vec!["FỌ"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_if_words_are_not_in_the_collator_order() {
+        let mut dawg = Dawg::with_collator(Alphabet::spanish());
+        // in code point order, but "Ñ" comes before "O" in Spanish
+        dawg.insert("OSO".to_string());
+        dawg.insert("ÑANDÚ".to_string());
+    }
+}
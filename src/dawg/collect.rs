@@ -0,0 +1,136 @@
+use std::cmp::Ordering;
+
+use crate::dawg::dawg::Dawg;
+
+#[cfg(test)]
+#[path = "./collect.test.rs"]
+mod collect_test;
+
+/// Builds a (finished) dawg from words in any order, they are sorted and duplicates (and empty words) removed first
+///
+/// ```rust
+/// use dawg::Dawg;
+///
+/// let lexicon = ["CAT", "BAT", "CAT", "ÒYÀ"].iter().collect::<Dawg>();
+/// assert_eq!(lexicon.words().collect::<Vec<_>>(), vec!["BAT", "CAT", "ÒYÀ"]);
+/// ```
+impl<T: AsRef<str>> FromIterator<T> for Dawg {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut dawg = Self::new();
+        dawg.extend(iter);
+        dawg.finish();
+        dawg
+    }
+}
+
+/// Adds words in any order to a dawg, they are sorted and duplicates (and empty words) removed first.
+///
+/// A dawg that is still being built (not yet [`Dawg::finish`]ed) takes the new words in place when they all come after the last word inserted,
+/// which costs no more than sorting them: it stays open, and must be finished like after [`Dawg::insert`]. Otherwise, and always for a finished dawg,
+/// the dawg is rebuilt from its words and the new ones, in time proportional to the size of the whole lexicon: extend a finished dawg with
+/// large batches rather than a word at a time. A rebuilt dawg is finished again if it was finished, the words keep their scores,
+/// the dawg keeps its settings (tokenizer, collator...), and the indexes that were built (infixes, gaddag, scanner) are built again
+///
+/// ```rust
+/// use dawg::Dawg;
+///
+/// let mut lexicon = Dawg::new();
+/// lexicon.insert_with_score("CAT".to_string(), 10);
+/// lexicon.finish();
+///
+/// lexicon.extend(["DOG", "BAT", "CAT"]);
+/// assert_eq!(lexicon.words().collect::<Vec<_>>(), vec!["BAT", "CAT", "DOG"]);
+/// assert_eq!(lexicon.score("CAT"), Some(10));
+/// ```
+impl<T: AsRef<str>> Extend<T> for Dawg {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut words = iter.into_iter().map(|word| self.tokenize(word)).filter(|letters| !letters.is_empty()).collect::<Vec<_>>();
+        words.sort_by(|a, b| self.compare_sequences(a, b));
+        words.dedup();
+        // the last word inserted is already in the dawg, and keeps its score
+        words.retain(|letters| *letters != self.previous_word);
+
+        let mut words = words.into_iter().peekable();
+        let Some(first) = words.peek() else {
+            return;
+        };
+
+        // the words of an open dawg all come before `previous_word` (which is empty when there are none)
+        let finished = self.finished;
+        if !finished && self.compare_sequences(&self.previous_word, first).is_lt() {
+            for letters in words {
+                self.insert_sequence(letters);
+            }
+            return;
+        }
+
+        // the words inserted so far are sealed before being read back
+        self.finish();
+
+        let existing = self.sequences().enumerate()
+            .map(|(rank, letters)| (letters, self.scores.get(rank).copied().unwrap_or(0)))
+            .collect::<Vec<_>>();
+
+        let indexes = (self.infixes.is_some(), self.gaddag.is_some(), self.scanner.is_some());
+        self.clear();
+
+        // both are sorted, a word already in the dawg comes before the same new word and keeps its score
+        let mut existing = existing.into_iter().peekable();
+        loop {
+            let next = match (existing.peek(), words.peek()) {
+                (None, None) => break,
+                (Some(_), None) => existing.next().unwrap(),
+                (None, Some(_)) => (words.next().unwrap(), 0),
+                (Some((old, _)), Some(new)) => match self.compare_sequences(old, new) {
+                    Ordering::Less => existing.next().unwrap(),
+                    Ordering::Equal => {
+                        words.next();
+                        existing.next().unwrap()
+                    }
+                    Ordering::Greater => (words.next().unwrap(), 0),
+                },
+            };
+            self.insert_sequence_with_score(next.0, next.1);
+        }
+
+        if !finished {
+            return;
+        }
+        self.finish();
+
+        if indexes.0 {
+            self.build_infix_index();
+        }
+        if indexes.1 {
+            self.build_gaddag();
+        }
+        if indexes.2 {
+            self.build_scanner();
+        }
+    }
+}
+
+impl Dawg {
+    /// Builds a (finished) dawg from words already in the order of the dawg, without sorting them (duplicates and empty words are skipped)
+    ///
+    /// # Panics
+    /// If a word comes before the previous one, like [`Dawg::insert`]
+    ///
+    /// ```rust
+    /// use dawg::Dawg;
+    ///
+    /// let lexicon = Dawg::from_sorted_iter(["BAT", "CAT", "CAT", "DOG"]);
+    /// assert_eq!(lexicon.words().count(), 3);
+    /// ```
+    pub fn from_sorted_iter<T: AsRef<str>>(iter: impl IntoIterator<Item = T>) -> Self {
+        let mut dawg = Self::new();
+        for word in iter {
+            let letters = dawg.tokenize(word);
+            if !letters.is_empty() {
+                dawg.insert_sequence(letters);
+            }
+        }
+        dawg.finish();
+        dawg
+    }
+}
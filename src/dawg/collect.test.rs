@@ -0,0 +1,107 @@
+#[cfg(test)]
+mod test_collect {
+    use crate::dawg::collation::Alphabet;
+    use crate::dawg::dawg::Dawg;
+
+    #[test]
+    fn should_collect_words_in_any_order() {
+        let dawg = vec!["CARS", "BAT", "CAR", "BAT", "", "ÒYÀ", "BATH"].into_iter().collect::<Dawg>();
+
+        assert_eq!(dawg.words().collect::<Vec<_>>(), vec!["BAT", "BATH", "CAR", "CARS", "ÒYÀ"]);
+        assert!(dawg.is_word("CAR", true).is_some());
+
+        let words = include_str!("../sample_words.txt").lines().collect::<Dawg>();
+        let mut sorted = include_str!("../sample_words.txt").lines().filter(|word| !word.is_empty()).map(String::from).collect::<Vec<_>>();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(words.words().collect::<Vec<_>>(), sorted);
+
+        assert_eq!(Vec::<String>::new().into_iter().collect::<Dawg>().words().count(), 0);
+    }
+
+    #[test]
+    fn should_build_from_sorted_words() {
+        let dawg = Dawg::from_sorted_iter(vec!["BAT".to_string(), "BATH".to_string(), "BATH".to_string(), "CAR".to_string()]);
+        assert_eq!(dawg.words().collect::<Vec<_>>(), vec!["BAT", "BATH", "CAR"]);
+
+        // the empty word comes first, and is skipped
+        let dawg = Dawg::from_sorted_iter(["", "A", "", "B"]);
+        assert_eq!(dawg.words().collect::<Vec<_>>(), vec!["A", "B"]);
+        assert_eq!(Dawg::from_sorted_iter([""]).words().count(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_if_the_words_are_not_sorted() {
+        Dawg::from_sorted_iter(["CAR", "BAT"]);
+    }
+
+    #[test]
+    fn should_extend_a_finished_dawg() {
+        let mut dawg = Dawg::new();
+        dawg.insert_with_score("BAT".to_string(), 7);
+        dawg.insert_with_score("CAR".to_string(), 3);
+        dawg.finish();
+        dawg.build_gaddag();
+
+        dawg.extend(["CARS", "ARC", "BAT"]);
+        assert_eq!(dawg.words().collect::<Vec<_>>(), vec!["ARC", "BAT", "CAR", "CARS"]);
+        assert_eq!(dawg.score("BAT"), Some(7));
+        assert_eq!(dawg.score("CAR"), Some(3));
+        assert_eq!(dawg.score("ARC"), Some(0));
+        // the gaddag is rebuilt with the new words
+        assert_eq!(dawg.extend_with("AR", "CS").collect::<Vec<_>>(), vec!["ARC", "CAR", "CARS"]);
+
+        // words inserted but not yet sealed are kept
+        let mut dawg = Dawg::new();
+        dawg.insert_with_score("DOG".to_string(), 5);
+        dawg.extend(vec!["CAT".to_string()]);
+        // the dawg is still being built
+        dawg.insert("EMU".to_string());
+        dawg.finish();
+        assert_eq!(dawg.words().collect::<Vec<_>>(), vec!["CAT", "DOG", "EMU"]);
+        assert_eq!(dawg.score("DOG"), Some(5));
+
+        // a finished dawg is finished again, even when it had no words
+        let mut dawg = Dawg::new();
+        dawg.finish();
+        dawg.extend(["CAT", "BAT"]);
+        assert_eq!(dawg.words().collect::<Vec<_>>(), vec!["BAT", "CAT"]);
+        assert_eq!(dawg.rank("CAT"), Some(1));
+        assert!(dawg.finished);
+    }
+
+    #[test]
+    fn should_insert_words_in_place_while_the_dawg_is_being_built() {
+        let mut dawg = Dawg::new();
+        dawg.insert_with_score("BAT".to_string(), 7);
+        dawg.extend(["CAT", "BAT", "", "ANT"]);
+        // ANT comes before BAT, so the dawg was rebuilt
+        dawg.extend(["EMU", "DOG", "DOG"]);
+        dawg.extend(["FOX"]);
+        dawg.extend(Vec::<String>::new());
+        dawg.finish();
+
+        assert_eq!(dawg.words().collect::<Vec<_>>(), vec!["ANT", "BAT", "CAT", "DOG", "EMU", "FOX"]);
+        assert_eq!(dawg.score("BAT"), Some(7));
+
+        // the last word inserted is not inserted again
+        let mut dawg = Dawg::new();
+        dawg.insert_with_score("BAT".to_string(), 7);
+        dawg.extend(["BAT", "CAT"]);
+        dawg.finish();
+        assert_eq!(dawg.words().collect::<Vec<_>>(), vec!["BAT", "CAT"]);
+        assert_eq!(dawg.score("BAT"), Some(7));
+    }
+
+    #[test]
+    fn should_keep_the_settings_of_the_dawg() {
+        // small letters sort with their capitals in this alphabet
+        let mut dawg = Dawg::with_collator(Alphabet::new(('A'..='Z').map(String::from)));
+        dawg.extend(["Zebra", "apple", "Mango"]);
+        dawg.extend(["banana"]);
+        dawg.finish();
+
+        assert_eq!(dawg.words().collect::<Vec<_>>(), vec!["apple", "banana", "Mango", "Zebra"]);
+    }
+}
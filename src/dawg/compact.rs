@@ -0,0 +1,347 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::dawg::dawg::Dawg;
+use crate::dawg::symbol::Tokenizer;
+use crate::node::node::Node;
+
+#[cfg(test)]
+#[path = "./compact.test.rs"]
+mod compact_test;
+
+/// An edge of a [`CompactDawg`], standing for a chain of one or more letters
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CompactEdge {
+    /// the letters on the edge joined together, the unary nodes of the original dawg are collapsed into it.
+    /// Edges leaving a node all start with different letters
+    label: String,
+    /// where each letter of the label ends, in bytes (the letters were split by the original dawg, and cannot be split again)
+    ends: Vec<u32>,
+    /// the index of the node this edge leads to
+    target: usize,
+}
+
+impl CompactEdge {
+    /// The number of letters on the edge
+    fn len(&self) -> usize {
+        self.ends.len()
+    }
+
+    /// Where letter `index` of the label starts, in bytes
+    fn start(&self, index: usize) -> usize {
+        match index {
+            0 => 0,
+            _ => self.ends[index - 1] as usize,
+        }
+    }
+
+    fn letter(&self, index: usize) -> Option<&str> {
+        let end = *self.ends.get(index)? as usize;
+        Some(&self.label[self.start(index)..end])
+    }
+
+    /// The letters of the label from letter `index` on
+    fn rest(&self, index: usize) -> &str {
+        &self.label[self.start(index)..]
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CompactNode {
+    terminal: bool,
+    /// the number of words reachable from this node (itself included)
+    count: usize,
+    /// sorted by letter (in the order of the dawg's collator)
+    edges: Vec<CompactEdge>,
+}
+
+/// Where a walk down the graph stopped: at a node, or part of the way through one of its edges
+#[derive(Debug, Clone, Copy)]
+struct Position {
+    node: usize,
+    /// the edge being walked and the number of its letters already read
+    edge: Option<(usize, usize)>,
+}
+
+/// A compacted dawg (CDAWG): chains of nodes that have a single child and end no word are collapsed into one edge labelled with all their letters
+/// It answers the same queries as the [`Dawg`] it is built from, with far fewer nodes when the lexicon has long words
+///
+/// ```rust
+/// use dawg::{CompactDawg, Dawg};
+///
+/// let mut words = vec!["CATASTROPHE", "CATASTROPHES", "CATERPILLAR", "DOG"];
+/// words.sort();
+///
+/// let mut lexicon = Dawg::new();
+/// for word in words {
+///     lexicon.insert(word.to_string());
+/// }
+/// lexicon.finish();
+///
+/// let compact = CompactDawg::new(&lexicon);
+///
+/// assert!(compact.node_count() < lexicon.node_count());
+/// assert_eq!(compact.is_word("CATERPILLAR", true), Some("CATERPILLAR".to_string()));
+/// assert_eq!(compact.lookup("CAT", true), Some(3));
+/// assert_eq!(compact.words().collect::<Vec<_>>(), lexicon.words().collect::<Vec<_>>());
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompactDawg {
+    /// the root is the first node
+    nodes: Vec<CompactNode>,
+    /// an empty dawg with the settings of the dawg this was built from, which splits and folds letters like it
+    #[serde(default)]
+    settings: Dawg,
+}
+
+impl CompactDawg {
+    /// Builds the compacted version of a closed (finished) dawg, words are split like in the dawg (see [`Dawg::tokenize`])
+    pub fn new(dawg: &Dawg) -> Self {
+        let mut compact = Self { nodes: vec![], settings: dawg.settings() };
+        compact.add(dawg, &dawg.get_root(), &mut HashMap::new());
+        compact
+    }
+
+    /// Adds `node` (and everything under it) unless it was already added, returning its index
+    /// `added` maps the ids of the dawg's nodes to their indexes
+    fn add(&mut self, dawg: &Dawg, node: &Node, added: &mut HashMap<usize, usize>) -> usize {
+        #[cfg(not(feature = "threading"))]
+        let current = node.borrow();
+        #[cfg(feature = "threading")]
+        let current = node.lock().unwrap();
+
+        if let Some(index) = added.get(&current.id) {
+            return *index;
+        }
+
+        let index = self.nodes.len();
+        added.insert(current.id, index);
+        self.nodes.push(CompactNode { terminal: current.terminal, count: current.count, edges: vec![] });
+
+        for (letter, child) in dawg.sorted_edges(&current) {
+            let mut letters = vec![letter];
+            let mut child = child;
+
+            // follow the chain of nodes with a single child that end no word
+            loop {
+                #[cfg(not(feature = "threading"))]
+                let next = { let next = child.borrow(); (!next.terminal && next.edges.len() == 1).then(|| next.sorted_edges().remove(0)) };
+                #[cfg(feature = "threading")]
+                let next = { let next = child.lock().unwrap(); (!next.terminal && next.edges.len() == 1).then(|| next.sorted_edges().remove(0)) };
+
+                match next {
+                    Some((next_letter, next_child)) => {
+                        letters.push(next_letter);
+                        child = next_child;
+                    }
+                    None => break,
+                }
+            }
+
+            let target = self.add(dawg, &child, added);
+            let ends = letters.iter().scan(0, |end, letter| { *end += letter.len() as u32; Some(*end) }).collect();
+            self.nodes[index].edges.push(CompactEdge { label: letters.concat(), ends, target });
+        }
+
+        index
+    }
+
+    /// The number of nodes in the graph
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Replaces the tokenizer used to split words, which must be the one of the dawg this was built from (see [`Dawg::set_tokenizer`]).
+    /// Tokenizers are not serialized, so this must be called again after deserializing a compacted dawg that was built with one
+    pub fn set_tokenizer(&mut self, tokenizer: impl Tokenizer<String> + Send + Sync + 'static) {
+        self.settings.set_tokenizer(tokenizer);
+    }
+
+    fn matches(&self, expected: &str, letter: &str, case_sensitive: bool) -> bool {
+        match case_sensitive {
+            true => expected == letter,
+            false => self.settings.case_folding.fold(expected) == self.settings.case_folding.fold(letter),
+        }
+    }
+
+    /// Returns every position reached by reading the letters of `word` from the root
+    /// (more than one when the search is case insensitive and the lexicon has different cases of the same letter)
+    fn find(&self, word: &[String], case_sensitive: bool) -> Vec<Position> {
+        let mut positions = vec![Position { node: 0, edge: None }];
+
+        for letter in word {
+            let mut next = vec![];
+
+            for position in positions {
+                match position.edge {
+                    None => {
+                        for (index, edge) in self.nodes[position.node].edges.iter().enumerate() {
+                            if edge.letter(0).is_some_and(|expected| self.matches(expected, letter, case_sensitive)) {
+                                next.push(self.advance(position.node, index, 1));
+                            }
+                        }
+                    }
+                    Some((index, read)) => {
+                        let edge = &self.nodes[position.node].edges[index];
+                        if edge.letter(read).is_some_and(|expected| self.matches(expected, letter, case_sensitive)) {
+                            next.push(self.advance(position.node, index, read + 1));
+                        }
+                    }
+                }
+            }
+
+            positions = next;
+        }
+
+        positions
+    }
+
+    /// The position after reading `read` letters of an edge, which is the edge's target when all of them were read
+    fn advance(&self, node: usize, edge: usize, read: usize) -> Position {
+        let edge_ref = &self.nodes[node].edges[edge];
+
+        match edge_ref.len() == read {
+            true => Position { node: edge_ref.target, edge: None },
+            false => Position { node, edge: Some((edge, read)) },
+        }
+    }
+
+    /// Given a specific word, check if the word exists in the lexicon (see [`Dawg::is_word`])
+    pub fn is_word(&self, word: impl AsRef<str>, case_sensitive: bool) -> Option<String> {
+        let found = self.find(&self.settings.tokenize(word.as_ref()), case_sensitive).into_iter()
+            .any(|position| position.edge.is_none() && self.nodes[position.node].terminal);
+
+        found.then(|| word.as_ref().to_owned())
+    }
+
+    /// Returns the number of words starting with `prefix`, or None if it is not a prefix of any word in the lexicon (see [`Dawg::lookup`])
+    pub fn lookup(&self, prefix: impl AsRef<str>, case_sensitive: bool) -> Option<usize> {
+        let positions = self.find(&self.settings.tokenize(prefix.as_ref()), case_sensitive);
+
+        if positions.is_empty() {
+            return None;
+        }
+
+        Some(positions.into_iter().map(|position| match position.edge {
+            None => self.nodes[position.node].count,
+            Some((edge, _)) => self.nodes[self.nodes[position.node].edges[edge].target].count,
+        }).sum())
+    }
+
+    /// Returns an iterator over all the words in the lexicon, in alphabetical order
+    pub fn words(&self) -> CompactWords<'_> {
+        CompactWords { dawg: self, stack: vec![(String::new(), 0)] }
+    }
+
+    /// Returns an iterator over the words starting with `prefix`, in alphabetical order
+    pub fn words_with_prefix(&self, prefix: impl AsRef<str>) -> CompactWords<'_> {
+        // the words start with the prefix as it is stored, not as it was given
+        let letters = self.settings.tokenize(prefix.as_ref());
+        let prefix = letters.concat();
+        let stack = self.find(&letters, true).first().map(|position| match position.edge {
+            None => (prefix.to_owned(), position.node),
+            Some((edge, read)) => {
+                let edge = &self.nodes[position.node].edges[edge];
+                (format!("{}{}", prefix, edge.rest(read)), edge.target)
+            }
+        });
+
+        CompactWords { dawg: self, stack: stack.into_iter().collect() }
+    }
+
+    /// Returns the position of `word` in the (alphabetically sorted) lexicon (see [`Dawg::rank`])
+    pub fn rank(&self, word: impl AsRef<str>) -> Option<usize> {
+        let letters = self.settings.tokenize(word.as_ref());
+        let mut letters = letters.iter().map(String::as_str).peekable();
+        let mut node = 0;
+        let mut rank = 0;
+
+        while letters.peek().is_some() {
+            let current = &self.nodes[node];
+            if current.terminal {
+                rank += 1;
+            }
+
+            let letter = *letters.peek().unwrap();
+            let mut next = None;
+
+            for edge in &current.edges {
+                if edge.letter(0) == Some(letter) {
+                    next = Some(edge);
+                    break;
+                }
+                rank += self.nodes[edge.target].count;
+            }
+
+            let edge = next?;
+            for index in 0..edge.len() {
+                if letters.next() != edge.letter(index) {
+                    return None;
+                }
+            }
+
+            node = edge.target;
+        }
+
+        self.nodes[node].terminal.then_some(rank)
+    }
+
+    /// Returns the word at position `rank` in the (alphabetically sorted) lexicon (see [`Dawg::word_at`])
+    pub fn word_at(&self, rank: usize) -> Option<String> {
+        let mut node = 0;
+        let mut rank = rank;
+        let mut word = String::new();
+
+        loop {
+            let current = &self.nodes[node];
+            if current.terminal {
+                if rank == 0 {
+                    return Some(word);
+                }
+                rank -= 1;
+            }
+
+            let mut next = None;
+            for edge in &current.edges {
+                let count = self.nodes[edge.target].count;
+                if rank < count {
+                    next = Some(edge);
+                    break;
+                }
+                rank -= count;
+            }
+
+            let edge = next?;
+            word.push_str(&edge.label);
+            node = edge.target;
+        }
+    }
+}
+
+/// A lazy iterator over the words of a [`CompactDawg`] in alphabetical order
+pub struct CompactWords<'a> {
+    dawg: &'a CompactDawg,
+    /// the nodes left to visit, and the word formed on the way to them (the next one to visit is last)
+    stack: Vec<(String, usize)>,
+}
+
+impl Iterator for CompactWords<'_> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((word, node)) = self.stack.pop() {
+            let current = &self.dawg.nodes[node];
+
+            for edge in current.edges.iter().rev() {
+                self.stack.push((format!("{}{}", word, edge.label), edge.target));
+            }
+
+            if current.terminal {
+                return Some(word);
+            }
+        }
+
+        None
+    }
+}
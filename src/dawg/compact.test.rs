@@ -0,0 +1,118 @@
+#[cfg(test)]
+mod test_compact {
+    use crate::dawg::compact::CompactDawg;
+    use crate::dawg::dawg::Dawg;
+    use crate::dawg::normalize::Normalization;
+    use crate::dawg::symbol::Tiles;
+
+    fn setup_dawg() -> Dawg {
+        let mut words = include_str!("../sample_words.txt").lines()
+            .chain(["BAM", "BAT", "BATH", "BATHE", "CAR", "CARS", "CATASTROPHE", "CATERPILLAR", "a", "alpa", "ÒYA", "AYÒ", "ayò"])
+            .map(|x| x.to_string())
+            .collect::<Vec<_>>();
+        words.sort();
+        words.dedup();
+
+        let mut dawg = Dawg::new();
+        for word in words {
+            dawg.insert(word);
+        }
+        dawg.finish();
+        dawg
+    }
+
+    #[test]
+    fn should_collapse_chains_of_single_child_nodes() {
+        let mut dawg = Dawg::new();
+        dawg.insert("ABCDEF".to_string());
+        dawg.finish();
+
+        assert_eq!(dawg.node_count(), 7);
+        assert_eq!(CompactDawg::new(&dawg).node_count(), 2);
+
+        let dawg = setup_dawg();
+        assert!(CompactDawg::new(&dawg).node_count() < dawg.node_count());
+    }
+
+    #[test]
+    fn should_answer_word_queries_like_the_dawg() {
+        let dawg = setup_dawg();
+        let compact = CompactDawg::new(&dawg);
+
+        for word in dawg.words() {
+            assert_eq!(compact.is_word(&word, true), Some(word.to_owned()));
+        }
+
+        for word in ["", "B", "BATHS", "CATASTROPH", "CATERPILLARS", "AYO", "ZZZ"] {
+            assert_eq!(compact.is_word(word, true), dawg.is_word(word, true));
+        }
+
+        assert_eq!(compact.is_word("caterpillar", false), Some("caterpillar".to_string()));
+        assert_eq!(compact.is_word("caterpillar", true), None);
+    }
+
+    #[test]
+    fn should_answer_prefix_queries_like_the_dawg() {
+        let dawg = setup_dawg();
+        let compact = CompactDawg::new(&dawg);
+
+        for prefix in ["", "A", "AA", "BAT", "CATA", "CATERP", "ÒY", "a"] {
+            #[cfg(not(feature = "threading"))]
+            let expected = dawg.lookup(prefix, true).map(|node| node.borrow().count);
+            #[cfg(feature = "threading")]
+            let expected = dawg.lookup(prefix, true).map(|node| node.lock().unwrap().count);
+
+            assert_eq!(compact.lookup(prefix, true), expected);
+            assert_eq!(compact.words_with_prefix(prefix).collect::<Vec<_>>(), dawg.words_with_prefix(prefix).collect::<Vec<_>>());
+        }
+
+        assert_eq!(compact.lookup("CATX", true), None);
+        assert_eq!(compact.words_with_prefix("CATX").count(), 0);
+        // "AYÒ" and "ayò"
+        assert_eq!(compact.lookup("Ayò", false), Some(2));
+    }
+
+    #[test]
+    fn should_iterate_and_rank_like_the_dawg() {
+        let dawg = setup_dawg();
+        let compact = CompactDawg::new(&dawg);
+
+        assert_eq!(compact.words().collect::<Vec<_>>(), dawg.words().collect::<Vec<_>>());
+
+        for (rank, word) in dawg.words().enumerate() {
+            assert_eq!(compact.rank(&word), Some(rank));
+            assert_eq!(compact.word_at(rank), Some(word));
+        }
+
+        assert_eq!(compact.rank("CATASTROPH"), None);
+        assert_eq!(compact.word_at(dawg.words().count()), None);
+    }
+
+    #[test]
+    fn should_split_words_like_the_dawg() {
+        let mut dawg = Dawg::with_tokenizer(Tiles::new(["LL", "CH"]));
+        let mut words = ["LLAMA", "LUNA", "CHAL", "CALLE"].map(String::from);
+        dawg.sort_words(&mut words);
+        for word in words {
+            dawg.insert(word);
+        }
+        dawg.finish();
+        let compact = CompactDawg::new(&dawg);
+
+        assert_eq!(compact.is_word("LLAMA", true), Some("LLAMA".to_string()));
+        assert_eq!(compact.is_word("llama", false), Some("llama".to_string()));
+        assert_eq!(compact.lookup("L", true), Some(1));
+        assert_eq!(compact.words_with_prefix("LL").collect::<Vec<_>>(), vec!["LLAMA"]);
+        for word in dawg.words() {
+            assert_eq!(compact.rank(&word), dawg.rank(&word));
+        }
+
+        let mut dawg = Dawg::with_normalization(Normalization::Nfd);
+        dawg.insert("O\u{300}YA".to_string());
+        dawg.finish();
+        let compact = CompactDawg::new(&dawg);
+
+        assert!(compact.is_word("\u{D2}YA", true).is_some());
+        assert_eq!(compact.words_with_prefix("\u{D2}").collect::<Vec<_>>(), vec!["O\u{300}YA"]);
+    }
+}
@@ -0,0 +1,137 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+
+use crate::dawg::dawg::Dawg;
+use crate::node::node::Node;
+
+#[cfg(test)]
+#[path = "./completion.test.rs"]
+mod completion_test;
+
+/// An entry on the frontier of the best-first search in [`Dawg::top_k`]
+/// `score` is either the score of a complete word, or the best score reachable from `node` (an upper bound)
+struct Candidate {
+    score: u64,
+    word: String,
+    /// the rank of the first word reached through `node` on this path, where the scores are looked up
+    rank: usize,
+    /// the position of every edge taken from the prefix among its siblings (in the order of the dawg),
+    /// which orders the candidates like the dawg orders its words
+    path: Vec<usize>,
+    node: Node,
+    complete: bool,
+}
+
+impl Candidate {
+    fn key(&self) -> (u64, Reverse<&Vec<usize>>, bool) {
+        (self.score, Reverse(&self.path), self.complete)
+    }
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    /// Highest score first, ties are broken in the order of the dawg,
+    /// and a complete word is preferred over a subtree that could only match its score
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key().cmp(&other.key())
+    }
+}
+
+impl Dawg {
+    /// Returns (at most) the `k` highest scoring words starting with `prefix`, alongside their scores
+    /// Words are ordered by score (highest first), and in the order of the dawg when scores are equal
+    ///
+    /// Every node records the highest score of the words going through it (computed in [`Dawg::finish`]), so this is a best-first
+    /// search that only opens the subtrees that can still produce one of the `k` results
+    ///
+    /// ```rust
+    /// use dawg::Dawg;
+    ///
+    /// let mut lexicon = Dawg::new();
+    /// lexicon.insert_with_score("CAR".to_string(), 40);
+    /// lexicon.insert_with_score("CARE".to_string(), 90);
+    /// lexicon.insert_with_score("CAREER".to_string(), 70);
+    /// lexicon.insert_with_score("CAT".to_string(), 10);
+    /// lexicon.finish();
+    ///
+    /// let result = lexicon.top_k("CAR", 2);
+    /// assert_eq!(result, vec![("CARE".to_string(), 90), ("CAREER".to_string(), 70)]);
+    /// ```
+    pub fn top_k(&self, prefix: impl AsRef<str>, k: usize) -> Vec<(String, u64)> {
+        let mut result = Vec::with_capacity(k);
+
+        if k == 0 {
+            return result;
+        }
+
+        let letters = self.tokenize(prefix);
+        let (Some(node), Some((rank, _))) = (self.lookup_sequence(&letters), self.prefix_range_sequence(&letters)) else {
+            return result;
+        };
+
+        #[cfg(not(feature = "threading"))]
+        let score = node.borrow().max_score;
+        #[cfg(feature = "threading")]
+        let score = node.lock().unwrap().max_score;
+
+        let mut frontier = BinaryHeap::new();
+        frontier.push(Candidate { score, word: letters.concat(), rank, path: vec![], node, complete: false });
+
+        while let Some(candidate) = frontier.pop() {
+            if candidate.complete {
+                result.push((candidate.word, candidate.score));
+
+                if result.len() == k {
+                    break;
+                }
+                continue;
+            }
+
+            #[cfg(not(feature = "threading"))]
+            let current = candidate.node.borrow();
+            #[cfg(feature = "threading")]
+            let current = candidate.node.lock().unwrap();
+
+            let mut rank = candidate.rank;
+
+            if current.terminal {
+                #[cfg(not(feature = "threading"))]
+                let node = std::rc::Rc::clone(&candidate.node);
+                #[cfg(feature = "threading")]
+                let node = std::sync::Arc::clone(&candidate.node);
+
+                let score = self.scores.get(rank).copied().unwrap_or(0);
+                frontier.push(Candidate { score, word: candidate.word.to_owned(), rank, path: candidate.path.to_owned(), node, complete: true });
+                rank += 1;
+            }
+
+            for (position, (letter, child)) in self.sorted_edges(&current).into_iter().enumerate() {
+                #[cfg(not(feature = "threading"))]
+                let (score, count) = { let child = child.borrow(); (child.max_score, child.count) };
+                #[cfg(feature = "threading")]
+                let (score, count) = { let child = child.lock().unwrap(); (child.max_score, child.count) };
+
+                let mut path = candidate.path.to_owned();
+                path.push(position);
+
+                frontier.push(Candidate { score, word: format!("{}{}", candidate.word, letter), rank, path, node: child, complete: false });
+                rank += count;
+            }
+        }
+
+        result
+    }
+}
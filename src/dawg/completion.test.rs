@@ -0,0 +1,97 @@
+#[cfg(test)]
+mod test_completion {
+    use crate::dawg::collation::Alphabet;
+    use crate::dawg::dawg::Dawg;
+
+    fn setup_dawg() -> Dawg {
+        let mut words = vec![
+            ("BAT", 5), ("BATH", 20), ("BATHE", 3), ("BATS", 5), ("CAR", 40), ("CARE", 90),
+            ("CAREER", 70), ("CAREERS", 15), ("CARS", 40), ("CAT", 10), ("CATS", 12),
+        ];
+        words.sort();
+
+        let mut dawg = Dawg::new();
+        for (word, score) in words {
+            dawg.insert_with_score(word.to_string(), score);
+        }
+        dawg.finish();
+        dawg
+    }
+
+    #[test]
+    fn should_return_the_highest_scoring_completions_first() {
+        let dawg = setup_dawg();
+
+        let received = dawg.top_k("CA", 3);
+        let expected = vec![("CARE".to_string(), 90), ("CAREER".to_string(), 70), ("CAR".to_string(), 40)];
+
+        assert_eq!(received, expected);
+    }
+
+    #[test]
+    fn should_break_ties_alphabetically() {
+        let dawg = setup_dawg();
+
+        assert_eq!(dawg.top_k("CAR", 4)[2..], [("CAR".to_string(), 40), ("CARS".to_string(), 40)]);
+        assert_eq!(dawg.top_k("BAT", 3), vec![("BATH".to_string(), 20), ("BAT".to_string(), 5), ("BATS".to_string(), 5)]);
+    }
+
+    #[test]
+    fn should_return_every_completion_when_k_is_larger_than_the_subtree() {
+        let dawg = setup_dawg();
+
+        assert_eq!(dawg.top_k("", 100).len(), 11);
+        assert_eq!(dawg.top_k("CAT", 10), vec![("CATS".to_string(), 12), ("CAT".to_string(), 10)]);
+        assert!(dawg.top_k("DOG", 10).is_empty());
+        assert!(dawg.top_k("CA", 0).is_empty());
+    }
+
+    #[test]
+    fn should_share_the_suffixes_of_words_with_different_scores() {
+        let dawg = setup_dawg();
+
+        // "BATS" and "CATS" share the "S" suffix but not the score
+        assert_eq!(dawg.score("BATS"), Some(5));
+        assert_eq!(dawg.score("CATS"), Some(12));
+        assert_eq!(dawg.score("CA"), None);
+
+        let unweighted = dawg.words().collect::<Dawg>();
+        assert_eq!(dawg.node_count(), unweighted.node_count());
+
+        // the "X" ending both words is a single node, reached from "A" and from "B"
+        let mut dawg = Dawg::new();
+        dawg.insert_with_score("AX".to_string(), 1);
+        dawg.insert_with_score("BX".to_string(), 100);
+        dawg.finish();
+
+        assert_eq!(dawg.node_count(), 3);
+        assert_eq!(dawg.top_k("A", 1), vec![("AX".to_string(), 1)]);
+        assert_eq!(dawg.top_k("", 2), vec![("BX".to_string(), 100), ("AX".to_string(), 1)]);
+    }
+
+    #[test]
+    fn unscored_words_are_returned_alphabetically() {
+        let mut dawg = Dawg::new();
+        for word in ["TEA", "TEAM", "TEN", "TENT"] {
+            dawg.insert(word.to_string());
+        }
+        dawg.finish();
+
+        let received = dawg.top_k("TE", 3).into_iter().map(|(word, _)| word).collect::<Vec<_>>();
+        assert_eq!(received, vec!["TEA", "TEAM", "TEN"]);
+    }
+
+    #[test]
+    fn should_break_ties_in_the_order_of_the_collator() {
+        let mut dawg = Dawg::with_collator(Alphabet::spanish());
+        let mut words = ["MAZO", "MAÑO", "MANO", "MA"].map(String::from);
+        dawg.sort_words(&mut words);
+        for word in words {
+            dawg.insert_with_score(word, 5);
+        }
+        dawg.finish();
+
+        let received = dawg.top_k("MA", 4).into_iter().map(|(word, _)| word).collect::<Vec<_>>();
+        assert_eq!(received, vec!["MA", "MANO", "MAÑO", "MAZO"]);
+    }
+}
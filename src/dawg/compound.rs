@@ -0,0 +1,184 @@
+use std::iter::once;
+
+use crate::dawg::dawg::Dawg;
+
+#[cfg(test)]
+#[path = "./compound.test.rs"]
+mod compound_test;
+
+/// How words are joined into compounds (see [`Dawg::decompose`])
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompoundRules {
+    /// The linking morphemes that may join two parts e.g. the "s" of "Arbeitsamt" (Arbeit + s + Amt)
+    pub linking: Vec<String>,
+    /// The least number of letters (see [`Dawg::tokenize`]) a part must have
+    pub min_length: usize,
+    /// Whether the first letter of a part may be in another case than in the lexicon
+    /// e.g. "dampf" in "Donaudampfschiff" is the noun "Dampf", "Hoch" in "Hochhaus" is the adjective "hoch"
+    pub recapitalize: bool,
+}
+
+impl Default for CompoundRules {
+    /// The German rules: the linking morphemes "s", "es" and "n", parts of 3 letters or more, and nouns lowercased inside compounds
+    fn default() -> Self {
+        Self { linking: vec!["s".to_string(), "es".to_string(), "n".to_string()], min_length: 3, recapitalize: true }
+    }
+}
+
+/// A part of a compound word
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompoundPart {
+    /// The part as it is in the lexicon
+    pub word: String,
+    /// The linking morpheme between this part and the next one, if any
+    pub linking: Option<String>,
+}
+
+/// A word being decomposed, kept split into letters
+struct Compound<'a> {
+    dawg: &'a Dawg,
+    letters: Vec<String>,
+    rules: &'a CompoundRules,
+    /// for every letter, the parts that start at it (see [`Compound::find_parts`]), found once and used by every decomposition
+    parts: Vec<Vec<(String, usize)>>,
+}
+
+impl<'a> Compound<'a> {
+    fn new(dawg: &'a Dawg, letters: Vec<String>, rules: &'a CompoundRules) -> Self {
+        let mut compound = Self { dawg, letters, rules, parts: vec![] };
+        compound.parts = (0..compound.letters.len()).map(|start| compound.find_parts(start)).collect();
+        compound
+    }
+
+    /// The ways the first letter of a part can be written in the lexicon
+    fn spellings(&self, letter: &str) -> Vec<String> {
+        let mut spellings = vec![letter.to_string()];
+
+        if self.rules.recapitalize {
+            let upper = letter.to_uppercase();
+            let other = if upper == letter { letter.to_lowercase() } else { upper };
+            if other != letter {
+                spellings.push(other);
+            }
+        }
+
+        spellings
+    }
+
+    /// The parts that start at the letter `start`, each with the letter it ends before
+    /// The dawg is walked once per spelling of the first letter, down the letters of the word, until no word continues with them
+    fn find_parts(&self, start: usize) -> Vec<(String, usize)> {
+        let mut parts = vec![];
+
+        for first in self.spellings(&self.letters[start]) {
+            let mut node = self.dawg.get_root();
+            let mut word = String::new();
+            let letters = once(first).chain(self.letters[start + 1..].iter().cloned());
+
+            for (end, letter) in (start + 1..).zip(letters) {
+                #[cfg(not(feature = "threading"))]
+                let next = node.borrow().edges.get(&letter).cloned();
+                #[cfg(feature = "threading")]
+                let next = node.lock().unwrap().edges.get(&letter).cloned();
+
+                let Some(next) = next else {
+                    break;
+                };
+                node = next;
+                word.push_str(&letter);
+
+                #[cfg(not(feature = "threading"))]
+                let terminal = node.borrow().terminal;
+                #[cfg(feature = "threading")]
+                let terminal = node.lock().unwrap().terminal;
+
+                if terminal && end - start >= self.rules.min_length {
+                    parts.push((word.to_owned(), end));
+                }
+            }
+        }
+
+        // shorter parts first
+        parts.sort_by_key(|(_, end)| *end);
+        parts
+    }
+
+    /// The linking morphemes written at the letter `start`, each with the letter it ends before
+    fn linkings(&self, start: usize) -> Vec<(String, usize)> {
+        self.rules.linking.iter().filter_map(|linking| {
+            let mut written = String::new();
+            for (index, letter) in self.letters.iter().enumerate().skip(start) {
+                written.push_str(letter);
+                if written == *linking {
+                    return Some((linking.to_owned(), index + 1));
+                }
+                if !linking.starts_with(&written) {
+                    break;
+                }
+            }
+            None
+        }).collect()
+    }
+
+    /// Adds to `found` every way to split the letters from `start` on into parts, following the parts already in `parts`
+    fn decompose(&self, start: usize, parts: &mut Vec<CompoundPart>, found: &mut Vec<Vec<CompoundPart>>) {
+        for (word, end) in self.parts[start].iter().cloned() {
+            if end == self.letters.len() {
+                if !parts.is_empty() {
+                    let mut decomposition = parts.clone();
+                    decomposition.push(CompoundPart { word, linking: None });
+                    found.push(decomposition);
+                }
+                continue;
+            }
+
+            parts.push(CompoundPart { word: word.to_owned(), linking: None });
+            self.decompose(end, parts, found);
+            parts.pop();
+
+            for (linking, after) in self.linkings(end) {
+                if after < self.letters.len() {
+                    parts.push(CompoundPart { word: word.to_owned(), linking: Some(linking) });
+                    self.decompose(after, parts, found);
+                    parts.pop();
+                }
+            }
+        }
+    }
+}
+
+impl Dawg {
+    /// Returns every way to split `word` into two or more words of the lexicon, following the `rules` for the parts and what joins them
+    /// Decompositions with shorter first parts come first, and a part without a linking morpheme comes before the same part with one
+    ///
+    /// ```rust
+    /// use dawg::{CompoundRules, Dawg};
+    ///
+    /// let mut words = vec!["Amt", "Arbeit", "Dampf", "Donau", "Schiff"];
+    /// words.sort();
+    ///
+    /// let mut lexicon = Dawg::new();
+    /// for word in words {
+    ///     lexicon.insert(word.to_string());
+    /// }
+    /// lexicon.finish();
+    ///
+    /// let rules = CompoundRules::default();
+    /// let parts = |word: &str| lexicon.decompose(word, &rules).into_iter()
+    ///     .map(|parts| parts.into_iter().map(|part| part.word).collect::<Vec<_>>())
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(parts("Donaudampfschiff"), vec![vec!["Donau", "Dampf", "Schiff"]]);
+    /// assert_eq!(parts("Arbeitsamt"), vec![vec!["Arbeit", "Amt"]]);
+    /// ```
+    pub fn decompose(&self, word: impl AsRef<str>, rules: &CompoundRules) -> Vec<Vec<CompoundPart>> {
+        let compound = Compound::new(self, self.tokenize(word), rules);
+        let mut found = vec![];
+
+        if !compound.letters.is_empty() {
+            compound.decompose(0, &mut vec![], &mut found);
+        }
+
+        found
+    }
+}
@@ -0,0 +1,83 @@
+#[cfg(test)]
+mod test_compound {
+    use crate::dawg::compound::{CompoundPart, CompoundRules};
+    use crate::dawg::dawg::Dawg;
+
+    fn setup_dawg() -> Dawg {
+        let mut words = vec!["Amt", "Arbeit", "Bahn", "Dampf", "Dampfschiff", "Donau", "Ei", "Eisen", "Haus", "Schale", "Schiff", "hoch", "Tür"];
+        words.sort();
+
+        let mut dawg = Dawg::new();
+        for word in words {
+            dawg.insert(word.to_string());
+        }
+        dawg.finish();
+        dawg
+    }
+
+    fn part(word: &str, linking: Option<&str>) -> CompoundPart {
+        CompoundPart { word: word.to_string(), linking: linking.map(|linking| linking.to_string()) }
+    }
+
+    #[test]
+    fn should_return_every_decomposition() {
+        let dawg = setup_dawg();
+        let rules = CompoundRules::default();
+
+        assert_eq!(dawg.decompose("Donaudampfschiff", &rules), vec![
+            vec![part("Donau", None), part("Dampf", None), part("Schiff", None)],
+            vec![part("Donau", None), part("Dampfschiff", None)],
+        ]);
+        assert_eq!(dawg.decompose("Eisenbahn", &rules), vec![vec![part("Eisen", None), part("Bahn", None)]]);
+        assert_eq!(dawg.decompose("Haustür", &rules), vec![vec![part("Haus", None), part("Tür", None)]]);
+        // a word of the lexicon on its own is not a compound
+        assert!(dawg.decompose("Dampf", &rules).is_empty());
+        assert!(dawg.decompose("Donauboot", &rules).is_empty());
+        assert!(dawg.decompose("", &rules).is_empty());
+    }
+
+    #[test]
+    fn should_join_parts_with_linking_morphemes() {
+        let dawg = setup_dawg();
+        let rules = CompoundRules::default();
+
+        assert_eq!(dawg.decompose("Arbeitsamt", &rules), vec![vec![part("Arbeit", Some("s")), part("Amt", None)]]);
+        // a linking morpheme only joins two parts
+        assert!(dawg.decompose("Arbeitamts", &rules).is_empty());
+
+        let rules = CompoundRules { linking: vec!["er".to_string()], ..CompoundRules::default() };
+        assert!(dawg.decompose("Arbeitsamt", &rules).is_empty());
+    }
+
+    #[test]
+    fn should_follow_the_rules_on_length_and_capitalization() {
+        let dawg = setup_dawg();
+
+        let rules = CompoundRules { linking: vec!["er".to_string()], ..CompoundRules::default() };
+        assert!(dawg.decompose("Eierschale", &rules).is_empty());
+        let rules = CompoundRules { min_length: 2, ..rules };
+        assert_eq!(dawg.decompose("Eierschale", &rules), vec![vec![part("Ei", Some("er")), part("Schale", None)]]);
+
+        let rules = CompoundRules::default();
+        assert_eq!(dawg.decompose("Hochhaus", &rules), vec![vec![part("hoch", None), part("Haus", None)]]);
+        let rules = CompoundRules { recapitalize: false, ..CompoundRules::default() };
+        assert!(dawg.decompose("Hochhaus", &rules).is_empty());
+        assert!(dawg.decompose("Donaudampfschiff", &rules).is_empty());
+        assert_eq!(dawg.decompose("DonauDampf", &rules), vec![vec![part("Donau", None), part("Dampf", None)]]);
+    }
+
+    #[test]
+    fn should_share_the_parts_between_decompositions() {
+        let mut dawg = Dawg::new();
+        for word in ["ab", "abab"] {
+            dawg.insert(word.to_string());
+        }
+        dawg.finish();
+
+        // every way to write 8 as a sum of 1s and 2s (the number of "ab" in each part): the 9th Fibonacci number
+        let rules = CompoundRules { linking: vec![], min_length: 2, recapitalize: false };
+        let found = dawg.decompose("ab".repeat(8), &rules);
+        assert_eq!(found.len(), 34);
+        assert!(found.iter().all(|parts| parts.iter().map(|part| part.word.as_str()).collect::<String>() == "ab".repeat(8)));
+    }
+}
@@ -0,0 +1,152 @@
+#[cfg(not(feature = "threading"))]
+use std::rc::Rc;
+#[cfg(feature = "threading")]
+use std::sync::Arc;
+
+use crate::dawg::dawg::Dawg;
+use crate::dawg::symbol::Symbol;
+use crate::node::node::Node;
+
+#[cfg(test)]
+#[path = "./cursor.test.rs"]
+mod cursor_test;
+
+/// A position in a dawg that moves one letter at a time, e.g. to check a word while it is being typed without going back to the root for every letter
+/// Obtained with [`Dawg::cursor`], it starts at the root (the empty word)
+///
+/// ```rust
+/// use dawg::Dawg;
+///
+/// let mut lexicon = Dawg::new();
+/// for word in ["CAR", "CARE", "CAT"] {
+///     lexicon.insert(word.to_string());
+/// }
+/// lexicon.finish();
+///
+/// let mut cursor = lexicon.cursor();
+/// assert!(cursor.push("C"));
+/// assert!(cursor.push("A"));
+/// assert_eq!(cursor.children(), vec!["R", "T"]);
+/// assert_eq!(cursor.count(), 3);
+///
+/// assert!(!cursor.push("X")); // nothing starts with "CAX", the cursor stays on "CA"
+/// assert!(cursor.push("R"));
+/// assert!(cursor.is_terminal());
+///
+/// assert_eq!(cursor.pop(), Some("R".to_string()));
+/// assert_eq!(cursor.word(), "CA");
+/// ```
+#[derive(Debug, Clone)]
+pub struct DawgCursor<'a, S: Symbol = String> {
+    dawg: &'a Dawg<S>,
+    /// the nodes from the root (first) to the current one (last)
+    nodes: Vec<Node<S>>,
+    /// the letters read so far, one less than `nodes`
+    letters: Vec<S>,
+}
+
+impl<'a, S: Symbol> DawgCursor<'a, S> {
+    pub(crate) fn new(dawg: &'a Dawg<S>) -> Self {
+        Self { dawg, nodes: vec![dawg.get_root()], letters: vec![] }
+    }
+
+    fn current(&self) -> &Node<S> {
+        self.nodes.last().unwrap()
+    }
+
+    /// Moves down the edge labelled `letter`, returns false (and stays in place) when no word continues with it.
+    /// The symbol is looked up as it is, see [`DawgCursor::push`] for text
+    pub fn push_symbol(&mut self, letter: impl Into<S>) -> bool {
+        let letter = letter.into();
+
+        #[cfg(not(feature = "threading"))]
+        let next = self.current().borrow().edges.get(&letter).map(Rc::clone);
+        #[cfg(feature = "threading")]
+        let next = self.current().lock().unwrap().edges.get(&letter).map(Arc::clone);
+
+        match next {
+            Some(node) => {
+                self.nodes.push(node);
+                self.letters.push(letter);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Moves back up one letter, returning it (None at the root)
+    pub fn pop(&mut self) -> Option<S> {
+        let letter = self.letters.pop()?;
+        self.nodes.pop();
+        Some(letter)
+    }
+
+    /// Whether the letters read so far form a word
+    pub fn is_terminal(&self) -> bool {
+        #[cfg(not(feature = "threading"))]
+        return self.current().borrow().terminal;
+        #[cfg(feature = "threading")]
+        return self.current().lock().unwrap().terminal;
+    }
+
+    /// The number of words starting with the letters read so far (the word they form included)
+    pub fn count(&self) -> usize {
+        #[cfg(not(feature = "threading"))]
+        return self.current().borrow().count;
+        #[cfg(feature = "threading")]
+        return self.current().lock().unwrap().count;
+    }
+
+    /// The letters that can be pushed next, in the order of the dawg
+    pub fn children(&self) -> Vec<S> {
+        #[cfg(not(feature = "threading"))]
+        let current = self.current().borrow();
+        #[cfg(feature = "threading")]
+        let current = self.current().lock().unwrap();
+
+        self.dawg.sorted_edges(&current).into_iter().map(|(letter, _)| letter).collect()
+    }
+
+    /// The letters read so far
+    pub fn letters(&self) -> &[S] {
+        &self.letters
+    }
+
+    /// The node the cursor is on
+    pub fn node(&self) -> Node<S> {
+        #[cfg(not(feature = "threading"))]
+        return Rc::clone(self.current());
+        #[cfg(feature = "threading")]
+        return Arc::clone(self.current());
+    }
+}
+
+impl DawgCursor<'_> {
+    /// Moves down the letters of `text`, normalized and split like the words of the dawg (see [`Dawg::tokenize`]) so that e.g. a tile
+    /// is pushed whole. Returns false (and stays in place) when no word continues with all of them. The search is case sensitive
+    pub fn push(&mut self, text: impl AsRef<str>) -> bool {
+        let depth = self.letters.len();
+
+        for letter in self.dawg.tokenize(text) {
+            if !self.push_symbol(letter) {
+                self.nodes.truncate(depth + 1);
+                self.letters.truncate(depth);
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// The word formed by the letters read so far
+    pub fn word(&self) -> String {
+        self.letters.concat()
+    }
+}
+
+impl<S: Symbol> Dawg<S> {
+    /// Returns a cursor at the root of the dawg (see [`DawgCursor`])
+    pub fn cursor(&self) -> DawgCursor<'_, S> {
+        DawgCursor::new(self)
+    }
+}
@@ -0,0 +1,117 @@
+#[cfg(test)]
+mod test_cursor {
+    use crate::dawg::dawg::Dawg;
+    use crate::dawg::normalize::Normalization;
+    use crate::dawg::symbol::Tiles;
+
+    fn setup_dawg() -> Dawg {
+        let mut words = vec!["BAM", "BAT", "BATH", "BATHE", "CAR", "CARS", "ÒYA", "AYÒ"];
+        words.sort();
+
+        let mut dawg = Dawg::new();
+        for word in words {
+            dawg.insert(word.to_string());
+        }
+        dawg.finish();
+        dawg
+    }
+
+    #[test]
+    fn should_follow_a_word_letter_by_letter() {
+        let dawg = setup_dawg();
+        let mut cursor = dawg.cursor();
+
+        assert_eq!(cursor.count(), 8);
+        assert!(!cursor.is_terminal());
+        assert_eq!(cursor.children(), vec!["A", "B", "C", "Ò"]);
+
+        for (letter, terminal, count) in [("B", false, 4), ("A", false, 4), ("T", true, 3), ("H", true, 2), ("E", true, 1)] {
+            assert!(cursor.push(letter));
+            assert_eq!(cursor.is_terminal(), terminal);
+            assert_eq!(cursor.count(), count);
+        }
+
+        assert_eq!(cursor.word(), "BATHE");
+        assert!(cursor.children().is_empty());
+        assert!(!cursor.push("S"));
+        assert_eq!(cursor.word(), "BATHE");
+    }
+
+    #[test]
+    fn should_move_back_up() {
+        let dawg = setup_dawg();
+        let mut cursor = dawg.cursor();
+
+        assert_eq!(cursor.pop(), None);
+        assert!(cursor.push("Ò"));
+        assert!(cursor.push("Y"));
+        assert_eq!(cursor.letters(), ["Ò", "Y"]);
+
+        assert_eq!(cursor.pop(), Some("Y".to_string()));
+        assert_eq!(cursor.pop(), Some("Ò".to_string()));
+        assert_eq!(cursor.pop(), None);
+        assert_eq!(cursor.count(), 8);
+    }
+
+    #[test]
+    fn should_clone_a_cursor_independently() {
+        let dawg = setup_dawg();
+        let mut cursor = dawg.cursor();
+        cursor.push("C");
+        cursor.push("A");
+
+        let mut other = cursor.clone();
+        assert!(other.push("R"));
+        assert!(other.is_terminal());
+        assert_eq!(cursor.word(), "CA");
+        assert_eq!(cursor.children(), vec!["R"]);
+    }
+
+    #[test]
+    fn should_walk_any_symbol_type() {
+        let mut dawg: Dawg<u32> = Dawg::default();
+        dawg.insert_sequence(vec![1, 2, 3]);
+        dawg.insert_sequence(vec![1, 4]);
+        dawg.finish();
+
+        let mut cursor = dawg.cursor();
+        assert!(cursor.push_symbol(1u32));
+        assert_eq!(cursor.children(), vec![2, 4]);
+        assert!(cursor.push_symbol(4u32));
+        assert!(cursor.is_terminal());
+        assert_eq!(cursor.letters(), [1, 4]);
+    }
+
+    #[test]
+    fn should_push_text_like_the_words_of_the_dawg() {
+        let mut dawg = Dawg::with_normalization(Normalization::Nfc);
+        dawg.insert("\u{D2}YA".to_string());
+        dawg.finish();
+
+        // "Ò" typed as "O" followed by a combining grave accent
+        let mut cursor = dawg.cursor();
+        assert!(cursor.push("O\u{300}"));
+        assert_eq!(cursor.letters(), ["\u{D2}"]);
+        assert!(cursor.push("YA"));
+        assert!(cursor.is_terminal());
+
+        // nothing is pushed when the text goes off the dawg part way
+        let mut cursor = dawg.cursor();
+        assert!(!cursor.push("\u{D2}YE"));
+        assert!(cursor.letters().is_empty());
+
+        let mut words = ["LLAVE", "LUZ"].map(String::from);
+        let mut dawg = Dawg::with_tokenizer(Tiles::new(["LL"]));
+        dawg.sort_words(&mut words);
+        for word in words {
+            dawg.insert(word);
+        }
+        dawg.finish();
+
+        let mut cursor = dawg.cursor();
+        assert!(cursor.push("LL"));
+        assert_eq!(cursor.letters(), ["LL"]);
+        assert!(!cursor.push("L"));
+        assert_eq!(cursor.children(), vec!["A"]);
+    }
+}
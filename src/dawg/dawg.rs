@@ -1,36 +1,79 @@
 use std::collections::HashSet;
-use std::{collections::HashMap, cmp};
+use std::collections::HashMap;
 
 #[cfg(not(feature = "threading"))]
 use std::rc::Rc;
-#[cfg(feature = "threading")]
 use std::sync::Arc;
 
 use serde::{Deserialize, Serialize};
-use unicode_segmentation::UnicodeSegmentation;
 
 use crate::node::node::{DawgWrapper, Node};
+use crate::dawg::anagram::Anagrams;
 use crate::dawg::search::SearchResult;
+use crate::dawg::symbol::{Graphemes, Symbol, Tokenizer};
 use crate::dawg::tridawg::TriDawg;
+use crate::dawg::infix::InfixIndex;
+use crate::dawg::gaddag::Gaddag;
+use crate::dawg::scan::Scanner;
+use crate::dawg::normalize::Normalization;
+use crate::dawg::case::CaseFolding;
+use crate::dawg::collation::Collator;
 
 
 #[cfg(test)]
 #[path = "./dawg.test.rs"]
 mod dawg_test;
 
+/// A Directed Acyclic Word Graph of words made of symbols of type `S`
+/// By default (`Dawg` or `Dawg<String>`) words are text split into letters (graphemes), but any [`Symbol`] works e.g.
+/// `Dawg<u8>` for bytes, `Dawg<char>`, or `Dawg<u32>` for sequences of token ids (see [`Dawg::insert_sequence`])
 #[derive(Debug, Clone, Deserialize, Serialize)]
-pub struct Dawg {
+#[serde(bound(deserialize = "S: Symbol + Deserialize<'de>"))]
+pub struct Dawg<S: Symbol = String> {
     /// The root of the dawg
-    root: Node,
+    root: Node<S>,
     /// The wrapper of the dawg (generates a new id for every new dawg node) (review this comment please)
     node: DawgWrapper,
-    minimized_nodes: HashMap<String, Node>,
-    unchecked_nodes: Vec<TriDawg>,
-    previous_word: String, // ??? Did I mean previous letter?
+    minimized_nodes: HashMap<String, Node<S>>,
+    unchecked_nodes: Vec<TriDawg<S>>,
+    pub(crate) previous_word: Vec<S>, // ??? Did I mean previous letter?
+    /// Whether the dawg was closed with [`Dawg::finish`] since a word was last inserted (dawgs serialized without it were finished)
+    #[serde(default = "was_finished")]
+    pub(crate) finished: bool,
+    /// The score of every word by rank, i.e. in the order the words were inserted (see [`Dawg::insert_with_score`]).
+    /// Scores are kept out of the nodes so that words with different scores still share them
+    #[serde(default)]
+    pub(crate) scores: Vec<u64>,
+    /// The suffix index used by `containing` (only present after `build_infix_index` has been called)
+    #[serde(default)]
+    pub(crate) infixes: Option<Box<InfixIndex>>,
+    /// The gaddag used by `placements` and `extend_with` (only present after `build_gaddag` has been called)
+    #[serde(default)]
+    pub(crate) gaddag: Option<Box<Gaddag>>,
+    /// The automaton used by `scan` (only present after `build_scanner` has been called)
+    #[serde(default)]
+    pub(crate) scanner: Option<Box<Scanner>>,
+    /// Splits words into letters, [`Graphemes`] when none was given (not serialized, see [`Dawg::set_tokenizer`])
+    #[serde(skip)]
+    pub(crate) tokenizer: Option<Arc<dyn Tokenizer<S> + Send + Sync>>,
+    /// The normalization applied to words before they are split (see [`Dawg::with_normalization`])
+    #[serde(default)]
+    pub(crate) normalization: Option<Normalization>,
+    /// How letters are compared by case insensitive searches (see [`Dawg::set_case_folding`])
+    #[serde(default)]
+    pub(crate) case_folding: CaseFolding,
+    /// Orders the letters, their natural order when none was given (not serialized, see [`Dawg::set_collator`])
+    #[serde(skip)]
+    pub(crate) collator: Option<Arc<dyn Collator<S> + Send + Sync>>,
 }
 
-impl Dawg {
-    pub fn new() -> Self {
+fn was_finished() -> bool {
+    true
+}
+
+/// An empty dawg over any [`Symbol`] (`Dawg::new` is the shorthand for the default `Dawg<String>`)
+impl<S: Symbol> Default for Dawg<S> {
+    fn default() -> Self {
         let mut dawg_wrapper = DawgWrapper::new();
 
         Self {
@@ -38,10 +81,21 @@ impl Dawg {
             node: dawg_wrapper,
             minimized_nodes: HashMap::new(),
             unchecked_nodes: vec![],
-            previous_word: String::new(),
+            previous_word: vec![],
+            finished: false,
+            scores: vec![],
+            infixes: None,
+            gaddag: None,
+            scanner: None,
+            tokenizer: None,
+            normalization: None,
+            case_folding: CaseFolding::default(),
+            collator: None,
         }
     }
+}
 
+impl<S: Symbol> Dawg<S> {
     /// Removes nodes in the unchecked nodes up to (down_to) e.g. 
     /// if there are 7 items in unchecked_nodes and `down_to` is 4,
     /// minimize would remove node 7, 6, and 5
@@ -82,7 +136,7 @@ impl Dawg {
                 #[cfg(not(feature = "threading"))]
                 self.minimized_nodes.insert(node, Rc::clone(current));
                 #[cfg(feature = "threading")]
-                self.minimized_nodes.insert(node, Arc::clone(&current));
+                self.minimized_nodes.insert(node, Arc::clone(current));
             }
 
             self.unchecked_nodes.pop();
@@ -93,35 +147,44 @@ impl Dawg {
     }
 
 
-    /// Adds a word into our Dawg
-    /// Panics if the word you're trying to insert is lesser than a previously inserted one
-    /// ** words are expected to have been sorted (alphabetical order) before insertion into the dawg
-    pub fn insert(&mut self, word: String) {
-        if self.previous_word > word {
+    /// Adds a sequence of symbols (a word) into our Dawg
+    /// Panics if the sequence you're trying to insert is lesser than a previously inserted one
+    /// ** sequences are expected to have been sorted before insertion into the dawg
+    ///
+    /// ```rust
+    /// use dawg::{Bytes, Dawg, Tokenizer};
+    ///
+    /// // DNA k-mers, one byte per base
+    /// let mut kmers = vec!["ACGT", "ACGA", "TTGA"].into_iter().map(|kmer| Bytes.tokenize(kmer)).collect::<Vec<_>>();
+    /// kmers.sort();
+    ///
+    /// let mut dna: Dawg<u8> = Dawg::default();
+    /// for kmer in kmers {
+    ///     dna.insert_sequence(kmer);
+    /// }
+    /// dna.finish();
+    ///
+    /// assert!(dna.contains_sequence(b"ACGA"));
+    /// assert_eq!(dna.sequences_with_prefix(b"AC").count(), 2);
+    /// ```
+    pub fn insert_sequence(&mut self, sequence: Vec<S>) {
+        self.insert_sequence_with_score(sequence, 0);
+    }
+
+    /// Adds a sequence of symbols into our Dawg alongside a score, see [`Dawg::insert_with_score`]
+    pub fn insert_sequence_with_score(&mut self, sequence: Vec<S>, score: u64) {
+        if self.compare_sequences(&self.previous_word, &sequence).is_gt() {
             panic!("Error: Please ensure all words are sorted before adding")
         }
 
-        let mut common_prefix = 0;
-
-        
-        let word_vec = word.graphemes(true).collect::<Vec<_>>();
-        let prev_word_vec = self.previous_word.graphemes(true).collect::<Vec<_>>();
-
-        let min_length = cmp::min(word_vec.len(), prev_word_vec.len());
-
-        for index in 0..min_length {
-            if word_vec[index] != prev_word_vec[index] {
-                break;
-            }
-            common_prefix += 1;
-        }
+        let common_prefix = sequence.iter().zip(self.previous_word.iter()).take_while(|(a, b)| a == b).count();
 
         // write out what this line does for easy onboarding
         self.minimize(common_prefix);
 
         // Get the remaining letters that are not a part of the common prefix
-        for index in common_prefix..word_vec.len() {
-            let letter = word_vec[index].to_owned();
+        for index in common_prefix..sequence.len() {
+            let letter = sequence[index].to_owned();
 
             // having established the common prefixes earlier (which we won't be duplicating)
             // we would extend the last node with the remaining letters from our new word
@@ -152,11 +215,21 @@ impl Dawg {
         let last_node = self.unchecked_nodes.last().unwrap();
         
         #[cfg(not(feature = "threading"))]
-        {last_node.child.as_ref().borrow_mut().terminal = true};
+        let mut last_node = last_node.child.as_ref().borrow_mut();
         #[cfg(feature = "threading")]
-        {last_node.child.as_ref().lock().unwrap().terminal = true};
+        let mut last_node = last_node.child.as_ref().lock().unwrap();
+
+        last_node.terminal = true;
+        drop(last_node);
 
-        self.previous_word = word;
+        // a word inserted again replaces its score
+        match self.scores.last_mut() {
+            Some(last) if self.previous_word == sequence => *last = score,
+            _ => self.scores.push(score),
+        }
+
+        self.previous_word = sequence;
+        self.finished = false;
     }
 
     /// Closes the dawg after all words have been inserted into it
@@ -169,60 +242,237 @@ impl Dawg {
         #[cfg(feature = "threading")]
         self.root.as_ref().lock().unwrap().num_reachable();
 
+        let root = self.get_root();
+        self.order_edges(&root);
+
+        if self.scores.iter().any(|score| *score != 0) {
+            let root = self.get_root();
+            self.bound_scores(&root, 0);
+        }
+
         self.minimized_nodes = HashMap::new();
         self.unchecked_nodes = vec![];
-        self.previous_word = String::new();
+        self.previous_word = vec![];
+        self.finished = true;
     }
 
-    fn find<'a>(&self, word: impl AsRef<str>, case_sensitive: bool) -> Option<SearchResult> {
-        let letters = word.as_ref().graphemes(true).collect::<Vec<_>>();
-        
+    /// Raises the `max_score` of `node` and of the nodes below it with the scores of the words reached through it,
+    /// the first of them being the word of rank `rank`. Returns the highest of those scores
+    fn bound_scores(&self, node: &Node<S>, rank: usize) -> u64 {
+        #[cfg(not(feature = "threading"))]
+        let (terminal, edges) = { let current = node.borrow(); (current.terminal, self.sorted_edges(&current)) };
+        #[cfg(feature = "threading")]
+        let (terminal, edges) = { let current = node.lock().unwrap(); (current.terminal, self.sorted_edges(&current)) };
+
+        let mut rank = rank;
+        let mut best = 0;
+
+        if terminal {
+            best = self.scores.get(rank).copied().unwrap_or(0);
+            rank += 1;
+        }
+
+        for (_, child) in edges {
+            best = best.max(self.bound_scores(&child, rank));
+
+            #[cfg(not(feature = "threading"))]
+            { rank += child.borrow().count; }
+            #[cfg(feature = "threading")]
+            { rank += child.lock().unwrap().count; }
+        }
+
+        #[cfg(not(feature = "threading"))]
+        let mut current = node.borrow_mut();
+        #[cfg(feature = "threading")]
+        let mut current = node.lock().unwrap();
+
+        current.max_score = current.max_score.max(best);
+        best
+    }
+
+    /// Returns the node reached by following `sequence` from the root, i.e. the node of a prefix of one or more sequences in the dawg
+    pub fn lookup_sequence(&self, sequence: &[S]) -> Option<Node<S>> {
+        let mut node = self.get_root();
+
+        for symbol in sequence {
+            #[cfg(not(feature = "threading"))]
+            let next = node.borrow().edges.get(symbol).map(Rc::clone)?;
+            #[cfg(feature = "threading")]
+            let next = node.lock().unwrap().edges.get(symbol).map(Arc::clone)?;
+
+            node = next;
+        }
+
+        Some(node)
+    }
+
+    /// Whether `sequence` was inserted into the dawg
+    pub fn contains_sequence(&self, sequence: &[S]) -> bool {
+        let Some(node) = self.lookup_sequence(sequence) else {
+            return false;
+        };
+
+        #[cfg(not(feature = "threading"))]
+        return node.borrow().terminal;
+        #[cfg(feature = "threading")]
+        return node.lock().unwrap().terminal;
+    }
+
+    /// Returns the root node of the dawgie
+    pub fn get_root(&self) -> Node<S> {
         #[cfg(not(feature = "threading"))]
-        let mut node: Node = Rc::clone(&self.root);
+        return Rc::clone(&self.root);
+
         #[cfg(feature = "threading")]
-        let mut node: Node = Arc::clone(&self.root);
+        return Arc::clone(&self.root);
+    }
 
-        for i in 0..letters.len() {
-            let letter = letters[i].to_owned();
+    /// Returns the number of (distinct) nodes in the dawg, the root included
+    pub fn node_count(&self) -> usize {
+        let mut seen = HashSet::new();
+        let mut stack = vec![self.get_root()];
 
+        while let Some(node) = stack.pop() {
             #[cfg(not(feature = "threading"))]
-            let keys = node.borrow().edges.keys().map(|x| x.to_owned()).collect::<Vec<_>>();
+            let current = node.borrow();
             #[cfg(feature = "threading")]
-            let keys = node.lock().unwrap().edges.keys().map(|x| x.to_owned()).collect::<Vec<_>>();
-
-            match case_sensitive {
-                true => {
-                    if keys.contains(&&letter) {
-                        #[cfg(not(feature = "threading"))]
-                        let next_node = Rc::clone(&node.as_ref().borrow().edges[&letter]);
-                        #[cfg(feature = "threading")]
-                        let next_node = Arc::clone(&node.lock().unwrap().edges[&letter]);
-
-                        node = next_node;
-                    } else {
-                        return None;
-                    }
-                }
-                false => {
-                    let letter = letter.to_uppercase();
-                    let letter_exists = keys.iter().position(|x| x.to_uppercase() == letter);
-
-                    if let Some(index) = letter_exists {
-                        let actual_letter = &keys[index];
-                        #[cfg(not(feature = "threading"))]
-                        let next_node = Rc::clone(&node.as_ref().borrow().edges[actual_letter]);
-                        #[cfg(feature = "threading")]
-                        let next_node = Arc::clone(&node.as_ref().lock().unwrap().edges[&actual_letter]);
-
-                        node = next_node;
-                    } else {
-                        return None;
-                    }
-                }
+            let current = node.lock().unwrap();
+
+            if seen.insert(current.id) {
+                stack.extend(current.sorted_edges().into_iter().map(|(_, child)| child));
             }
         }
 
-        return Some(SearchResult::new(node, word.as_ref().to_owned()));
+        seen.len()
+    }
+
+    /// Returns an empty dawg with the settings of this one (tokenizer, normalization, case folding, collator),
+    /// which splits, folds and orders letters exactly like it
+    pub(crate) fn settings(&self) -> Self {
+        Self {
+            tokenizer: self.tokenizer.clone(),
+            normalization: self.normalization,
+            case_folding: self.case_folding,
+            collator: self.collator.clone(),
+            ..Self::default()
+        }
+    }
+
+    /// Empties the dawg so that it can be built again, its settings (tokenizer, collator...) are kept but not its indexes
+    pub(crate) fn clear(&mut self) {
+        let mut dawg_wrapper = DawgWrapper::new();
+
+        self.root = dawg_wrapper.create();
+        self.node = dawg_wrapper;
+        self.minimized_nodes = HashMap::new();
+        self.unchecked_nodes = vec![];
+        self.previous_word = vec![];
+        self.finished = false;
+        self.scores = vec![];
+        self.infixes = None;
+        self.gaddag = None;
+        self.scanner = None;
+    }
+
+    /// Returns the number of nodes created so far, the ones merged away while minimizing included
+    pub(crate) fn created_nodes(&self) -> usize {
+        self.node.created()
+    }
+
+    /// Returns the number of nodes in the register of minimized nodes (the ones new nodes are checked against)
+    pub(crate) fn register_size(&self) -> usize {
+        self.minimized_nodes.len()
+    }
+}
+
+impl Dawg {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a dawg that splits words with `tokenizer` instead of graphemes e.g. [`Tiles`](crate::Tiles) for multi-letter tiles.
+    /// The tokenizer is used everywhere words are split: insertion, lookup, anagrams and pattern search
+    /// Words must then be inserted in the order given by [`Dawg::sort_words`], which is not always the order of `Vec::sort`
+    ///
+    /// ```rust
+    /// use dawg::{Dawg, Tiles};
+    ///
+    /// let mut lexicon = Dawg::with_tokenizer(Tiles::new(["CH", "LL", "RR"]));
+    /// for word in ["CALLA", "CALLAR", "LLAMA"] {
+    ///     lexicon.insert(word.to_string());
+    /// }
+    /// lexicon.finish();
+    ///
+    /// // "LL" is a single tile, two "L" tiles do not make it
+    /// assert_eq!(lexicon.find_anagrams("LLAAM").collect::<Vec<_>>(), vec!["LLAMA"]);
+    /// assert_eq!(lexicon.extend_with("", "CALLA").collect::<Vec<_>>(), vec!["CALLA"]);
+    /// assert_eq!(lexicon.extend_with("", "CALAL").count(), 0);
+    /// ```
+    pub fn with_tokenizer(tokenizer: impl Tokenizer<String> + Send + Sync + 'static) -> Self {
+        let mut dawg = Self::default();
+        dawg.set_tokenizer(tokenizer);
+        dawg
+    }
+
+    /// Replaces the tokenizer used to split words (see [`Dawg::with_tokenizer`]).
+    /// Tokenizers are not serialized, so this must be called again after deserializing a dawg that was built with one
+    pub fn set_tokenizer(&mut self, tokenizer: impl Tokenizer<String> + Send + Sync + 'static) {
+        self.tokenizer = Some(Arc::new(tokenizer));
+    }
+
+    /// Splits `word` into the letters (tiles) of this dawg, after normalizing it if a [`Normalization`] was set
+    pub fn tokenize(&self, word: impl AsRef<str>) -> Vec<String> {
+        let word = self.normalize(word);
+
+        match &self.tokenizer {
+            Some(tokenizer) => tokenizer.tokenize(&word),
+            None => Graphemes.tokenize(&word),
+        }
+    }
+
+    /// Splits off the start of `text` that the tokenizer splits on its own (see [`Tokenizer::first_len`]),
+    /// returning its letters (normalized like [`Dawg::tokenize`]) and its length in bytes in `text`, or None when `text` is empty
+    pub(crate) fn first_letters(&self, text: &str) -> Option<(Vec<String>, usize)> {
+        let length = match &self.tokenizer {
+            Some(tokenizer) => tokenizer.first_len(text),
+            None => Graphemes.first_len(text),
+        };
+
+        // at least one char is split off, and never half of one
+        let length = (length.max(1)..=text.len()).find(|end| text.is_char_boundary(*end))?;
+        Some((self.tokenize(&text[..length]), length))
+    }
+
+    /// Adds a word into our Dawg
+    /// Panics if the word you're trying to insert is lesser than a previously inserted one
+    /// ** words are expected to have been sorted (alphabetical order) before insertion into the dawg.
+    /// With a tokenizer or a collator, sort them with [`Dawg::sort_words`]: e.g. with a "LL" tile "LUNA" comes before "LLAMA", unlike with `Vec::sort`
+    pub fn insert(&mut self, word: String) {
+        self.insert_with_score(word, 0);
+    }
+
+    /// Adds a word into our Dawg alongside a score (e.g. its frequency), used to rank completions in [`Dawg::top_k`]
+    /// Follows the same ordering rules as [`Dawg::insert`]
+    pub fn insert_with_score(&mut self, word: String, score: u64) {
+        let letters = self.tokenize(&word);
+        self.insert_sequence_with_score(letters, score);
+    }
+
+    fn find(&self, word: impl AsRef<str>, case_sensitive: bool) -> Option<SearchResult> {
+        let node = match case_sensitive {
+            true => self.lookup_sequence(&self.tokenize(&word))?,
+            false => self.find_ignoring_case(&word)?,
+        };
+
+        Some(SearchResult::new(node, word.as_ref().to_owned()))
+    }
+
+    /// Applies the [`Normalization`] of this dawg (if any) to `word`
+    pub(crate) fn normalize(&self, word: impl AsRef<str>) -> String {
+        match self.normalization {
+            Some(normalization) => normalization.apply(word.as_ref()),
+            None => word.as_ref().to_owned(),
+        }
     }
 
     /// Given a specific word, check if the word exists in the lexicon (Allowing search to be case sensitive or insensitive)
@@ -264,16 +514,24 @@ impl Dawg {
         return None;
     }
 
-    /// Returns the root node of the dawgie
-    pub fn get_root(&self) -> Node {
-        #[cfg(not(feature = "threading"))]
-        return Rc::clone(&self.root);
+    /// Returns the score a word was inserted with (see [`Dawg::insert_with_score`]), or None if it is not a word in the lexicon
+    pub fn score(&self, word: impl AsRef<str>) -> Option<u64> {
+        self.sequence_score(&self.tokenize(word))
+    }
 
-        #[cfg(feature = "threading")]
-        return Arc::clone(&self.root);
+    /// Returns the score of the word made of `letters`, see [`Dawg::score`]
+    pub(crate) fn sequence_score(&self, letters: &[String]) -> Option<u64> {
+        if !self.contains_sequence(letters) {
+            return None;
+        }
+
+        let (rank, _) = self.prefix_range_sequence(letters)?;
+        Some(self.scores.get(rank).copied().unwrap_or(0))
     }
 
     /// find out if word is a prefix of anything in the dictionary
+    /// A case insensitive search can reach more than one node (e.g. through "a" and "A"), this returns the one ending a word if any:
+    /// use [`Dawg::lookup_all`] to get all of them
     pub fn lookup<'a>(&self, word: impl AsRef<str>, case_sensitive: bool) -> Option<Node> {
         let result = self.find(word, case_sensitive);
 
@@ -285,85 +543,32 @@ impl Dawg {
     }
 
 
-    /// this search is case sensitive
-    fn anagrams(&self, current: String, remaining: &Vec<&str>) -> Vec<String> {
-        if remaining.is_empty() {
-            if let Some(formed) = self.is_word(&current, true) {
-                return vec![formed];
-            } else {
-                return Vec::with_capacity(0)
-            };
-        }
-
-        let mut words: HashSet<String> = HashSet::new();
-
-        
-        for (index, letter) in remaining.iter().enumerate() {
-            let mut received = remaining.to_vec();
-            
-            let possible = format!("{}{}", current, letter);
-
-            // remove the letter
-            let _ = &received.remove(index);
-
-            let result = self.anagrams(possible, &received);
-            words.extend(result);
-        }
-
-
-        words.into_iter().collect()
-    }
-
-    /// Gets all valid anagrams of the word provided
-    /// e.g "ATE" would return vec!["EAT", "TEA", "ATE"] asumming the dictionary you loaded the dawg with contains all these words
-    pub fn find_anagrams(&self, word: impl AsRef<str>) -> Vec<String> {
-
-        let letters = word.as_ref().graphemes(true).collect::<Vec<_>>();
-        let result = self.anagrams(String::from(""), &letters);
-
-        return result;
-    }
-
-
-    /// Extends a provided prefix (`extend`) to the right using the letters you provided
-    /// e.g given "PICK" as extend and "YEDTUREI" as the letters, this function would return results like
-    fn word_generator(&self, extend: impl AsRef<str>, letters: &Vec<&str>) -> Vec<String> {
-        let mut words: HashSet<String> = HashSet::new();
-
-        if let Some(word) = self.find(&extend, true) {
-            #[cfg(feature = "threading")]
-            let is_terminal = word.node.lock().unwrap().terminal;
-            #[cfg(not(feature = "threading"))]
-            let is_terminal = word.node.borrow().terminal;
-            
-            if is_terminal {
-                words.insert(word.word);
-            }
-        }
-
-        if letters.is_empty() {
-            return words.into_iter().collect();
-        }
-
-        for i in 0..letters.len() {
-            let mut local_letters = letters.to_owned();
-            let letter = local_letters[i];
-            local_letters.remove(i);
-
-            let possible_word = format!("{}{}", &extend.as_ref(), letter);
-            
-            let result = self.word_generator(&possible_word, &local_letters);
-            words.extend(result);
-        }
-
-        words.into_iter().collect()
+    /// Returns the words that use exactly the letters of `word` (all of them), in the order of the dawg (alphabetical unless it has a collator)
+    /// e.g "ATE" would return "ATE", "EAT", "ETA" and "TEA" asumming the dictionary you loaded the dawg with contains all these words.
+    /// The search is case sensitive, and every anagram is returned once even when `word` repeats a letter
+    ///
+    /// ```rust
+    /// use dawg::Dawg;
+    ///
+    /// let mut words = vec!["ATE", "EAT", "ETA", "TEA", "TEE", "EATS"];
+    /// words.sort();
+    ///
+    /// let mut lexicon = Dawg::new();
+    /// for word in words {
+    ///     lexicon.insert(word.to_string());
+    /// }
+    /// lexicon.finish();
+    ///
+    /// assert_eq!(lexicon.find_anagrams("TEA").collect::<Vec<_>>(), vec!["ATE", "EAT", "ETA", "TEA"]);
+    /// ```
+    pub fn find_anagrams(&self, word: impl AsRef<str>) -> Anagrams<'_> {
+        Anagrams::new(self, self.tokenize(word), true, vec![])
     }
 
-
-    /// (Highly inefficient approach: O(n!))
-    /// This is a really expensive/brute-force approach, if you have a better suggestion, please reach out or raise a PR
-    /// Returns all the possible combination of words that can be formed when the provided `extend` variable is extended either to the right or left
-    /// e.g. given "IST" as prefix and ""
+    /// Returns the words that can be formed with the letters of `extend` and (some or all of) the letters of `with`, and contain `extend`
+    /// i.e. the ways to extend `extend` to the right or left, in the order of the dawg (alphabetical unless it has a collator).
+    /// The search is case sensitive, every word is returned once even when letters repeat, and [`BLANK`](crate::BLANK) ("?") in `with` stands for any letter.
+    /// When the gaddag has been built ([`Dawg::build_gaddag`]) the words are grown from `extend`, otherwise the whole lexicon is walked
     /// ```rust
     /// use dawg::Dawg;
     /// 
@@ -378,31 +583,25 @@ impl Dawg {
     /// 
     /// lexicon.finish(); // always remember to close the dawg when you're done inserting;
     /// 
-    /// // assuming we want to see all the possible ways to extend "IST" with the letters "SENTILLCH";
-    /// let mut result = lexicon.extend_with("IST", "LHENSC"); // would return vec!["SCHIST", "LISTEN"]
-    /// result.sort();
+    /// // assuming we want to see all the possible ways to extend "IST" with the letters "LHENSC";
+    /// let result = lexicon.extend_with("IST", "LHENSC").collect::<Vec<_>>();
     /// 
     /// let expected = vec!["LISTEN", "SCHIST"];
     /// assert_eq!(result, expected);
     /// ```
-    pub fn extend_with(&self, extend: impl AsRef<str>, with: impl AsRef<str>) -> Vec<String> {
-        let mut result: Vec<String> = Vec::new();
-        let combined = format!("{}{}", with.as_ref(), extend.as_ref());
-        let letters = combined.graphemes(true).collect::<Vec<_>>();
-
-        let words = self.word_generator("", &letters);
-
-        if extend.as_ref().len() > 0 {
-            for word in words {
-                if word.contains(&extend.as_ref()) {
-                    result.push(word);
-                }
-            }
-        } else {
-            result = words;
+    pub fn extend_with(&self, extend: impl AsRef<str>, with: impl AsRef<str>) -> Anagrams<'_> {
+        let extend = self.tokenize(extend);
+
+        if self.gaddag.is_some() && !extend.is_empty() {
+            let mut words = self.placements(extend.concat(), with).into_iter().map(|placement| placement.word).collect::<Vec<_>>();
+            // placements are sorted by word, a word with the infix in more than one place comes up more than once
+            words.dedup();
+            return Anagrams::found(self, words);
         }
-        
-        return result;
 
+        let mut letters = self.tokenize(with);
+        letters.extend(extend.iter().cloned());
+
+        Anagrams::new(self, letters, false, extend)
     }
 }
@@ -34,7 +34,7 @@ mod test_dawg {
 
         assert_eq!(dawg.minimized_nodes.len(), 0);
         assert_eq!(dawg.unchecked_nodes.len(), 0);
-        assert_eq!(dawg.previous_word, String::new());
+        assert_eq!(dawg.previous_word, Vec::<String>::new());
         #[cfg(not(feature = "threading"))]
         {   
             assert_eq!(dawg.root.borrow().count, 0);
@@ -70,7 +70,7 @@ mod test_dawg {
 
             assert_eq!(dawg.minimized_nodes.len(), 0);
             assert_eq!(dawg.unchecked_nodes.len(), word.len());
-            assert_eq!(dawg.previous_word, word);
+            assert_eq!(dawg.previous_word.concat(), word);
         }
 
         #[test]
@@ -210,40 +210,53 @@ mod test_dawg {
         fn should_return_all_the_possible_angrams() {
             let dawg = setup_dawg();
 
-            let mut received = dawg.find_anagrams("LISTEN");
-            let mut expected = vec!["LISTEN".to_string(), "SILENT".to_string()];
-            
-            received.sort();
-            expected.sort();
+            let received = dawg.find_anagrams("LISTEN").collect::<Vec<_>>();
+            let expected = vec!["LISTEN".to_string(), "SILENT".to_string()];
 
             assert_eq!(expected, received);
 
-            let mut received = dawg.find_anagrams("EAT");
-            let mut expected = vec!["EAT".to_string(), "TEA".to_string(), "ATE".to_string(), "ETA".to_string()];
-
-            received.sort();
-            expected.sort();
+            let received = dawg.find_anagrams("EAT").collect::<Vec<_>>();
+            let expected = vec!["ATE".to_string(), "EAT".to_string(), "ETA".to_string(), "TEA".to_string()];
 
             assert_eq!(expected, received);
 
-            let mut received = dawg.find_anagrams("AYÒ"); // (re do)
-            let mut expected = vec!["AYÒ".to_string(), "ÒYA".to_string()]; // ÒYA (do re)
-            
-            received.sort();
-            expected.sort();
+            let received = dawg.find_anagrams("AYÒ").collect::<Vec<_>>(); // (re do)
+            let expected = vec!["AYÒ".to_string(), "ÒYA".to_string()]; // ÒYA (do re)
 
             assert_eq!(expected, received);
 
 
 
-            let mut received = dawg.find_anagrams("AYÓ"); // (re mi) - same letters different diacritical/tonal marks
+            let received = dawg.find_anagrams("AYÓ").collect::<Vec<_>>(); // (re mi) - same letters different diacritical/tonal marks
             let expected: Vec<String> = vec![];
-            
-            received.sort();
 
             assert_eq!(expected, received);
         }
 
+        #[test]
+        fn should_return_every_anagram_once_even_with_repeated_letters() {
+            let dawg = setup_dawg();
+
+            // "SILLY" has two Ls, swapping them must not give a second "SILLY"
+            assert_eq!(dawg.find_anagrams("YLLIS").collect::<Vec<_>>(), vec!["SILLY"]);
+            assert_eq!(dawg.find_anagrams("SILENTS").count(), 0);
+
+            let received = dawg.extend_with("", "SILENTSL").collect::<Vec<_>>();
+            let mut sorted = received.clone();
+            sorted.sort();
+            sorted.dedup();
+            assert_eq!(received, sorted);
+        }
+
+        #[test]
+        fn should_return_anagrams_lazily() {
+            let dawg = setup_dawg();
+
+            let mut anagrams = dawg.find_anagrams("TEA");
+            assert_eq!(anagrams.next(), Some("ATE".to_string()));
+            assert_eq!(anagrams.next(), Some("EAT".to_string()));
+        }
+
 
         #[test]
         fn should_return_all_valid_formable_words() {
@@ -260,8 +273,7 @@ mod test_dawg {
             let dawg = setup_dawg();
 
             for (prefix, letters, expected) in test_case {
-                let mut received = dawg.extend_with(prefix, &letters);
-                received.sort();
+                let received = dawg.extend_with(prefix, &letters).collect::<Vec<_>>();
 
                 assert_eq!(expected, received);
             }
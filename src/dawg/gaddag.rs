@@ -0,0 +1,220 @@
+use serde::{Deserialize, Serialize};
+
+use crate::dawg::anagram::{Anagrams, Rack};
+use crate::dawg::dawg::Dawg;
+use crate::node::node::Node;
+
+#[cfg(test)]
+#[path = "./gaddag.test.rs"]
+mod gaddag_test;
+
+/// Separates the reversed start of a word from the rest of it in a [`Gaddag`] (no letter is ever empty)
+const SEPARATOR: &str = "";
+
+/// A word that can be played around a fixed infix, see [`Dawg::placements`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Placement {
+    pub word: String,
+    /// the position (in letters) of the infix in the word
+    pub start: usize,
+    /// the positions (in letters) of the letters spelled with blanks
+    pub blanks: Vec<usize>,
+}
+
+/// A GADDAG of a lexicon: every word "ABCD" is stored once for every way of splitting it, as the reversed start of the word,
+/// a separator, and the rest of the word ("A^BCD", "BA^CD", "CBA^D" and "DCBA^").
+/// Any infix can then be found with a single walk (its letters reversed), and words grown from it to the left, then to the right
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Gaddag {
+    paths: Dawg,
+}
+
+impl Gaddag {
+    /// Builds the gaddag of a closed (finished) dawg
+    pub fn new(dawg: &Dawg) -> Self {
+        let mut paths = dawg.sequences().flat_map(|letters| {
+            (1..=letters.len()).map(move |split| {
+                let mut path = letters[..split].iter().rev().cloned().collect::<Vec<_>>();
+                path.push(SEPARATOR.to_string());
+                path.extend(letters[split..].iter().cloned());
+                path
+            }).collect::<Vec<_>>()
+        }).collect::<Vec<_>>();
+
+        paths.sort();
+
+        let mut gaddag = Self::default();
+        for path in paths {
+            gaddag.paths.insert_sequence(path);
+        }
+        gaddag.paths.finish();
+        gaddag
+    }
+
+    /// Returns every way of playing the (already tokenized) `infix` with the letters of `rack`, in no particular order
+    fn placements(&self, infix: &[String], rack: Rack) -> Vec<Placement> {
+        let reversed = infix.iter().rev().cloned().collect::<Vec<_>>();
+        let Some(node) = self.paths.lookup_sequence(&reversed) else { return vec![] };
+
+        let mut search = PlacementSearch { infix, found: vec![] };
+        search.grow_left(&node, &mut vec![], rack);
+        search.found
+    }
+}
+
+/// The placements found so far by a walk down a [`Gaddag`]
+struct PlacementSearch<'a> {
+    infix: &'a [String],
+    found: Vec<Placement>,
+}
+
+impl PlacementSearch<'_> {
+    fn edges(node: &Node) -> (bool, Vec<(String, Node)>) {
+        #[cfg(not(feature = "threading"))]
+        let current = node.borrow();
+        #[cfg(feature = "threading")]
+        let current = node.lock().unwrap();
+
+        (current.terminal, current.sorted_edges())
+    }
+
+    /// Adds letters before the infix (`left` holds them from the closest to the infix, and whether a blank was used for each)
+    fn grow_left(&mut self, node: &Node, left: &mut Vec<(String, bool)>, rack: Rack) {
+        let (_, edges) = Self::edges(node);
+
+        for (letter, child) in edges {
+            if letter == SEPARATOR {
+                self.grow_right(&child, left, &mut vec![], rack.to_owned());
+                continue;
+            }
+
+            let mut rack = rack.to_owned();
+            if let Some(blank) = rack.take(&letter) {
+                left.push((letter, blank));
+                self.grow_left(&child, left, rack);
+                left.pop();
+            }
+        }
+    }
+
+    /// Adds letters after the infix, once the start of the word is complete
+    fn grow_right(&mut self, node: &Node, left: &[(String, bool)], right: &mut Vec<(String, bool)>, rack: Rack) {
+        let (terminal, edges) = Self::edges(node);
+
+        if terminal {
+            let start = left.len();
+            let before = left.iter().rev();
+            let after = right.iter();
+
+            let mut word = before.clone().map(|(letter, _)| letter.as_str()).collect::<String>();
+            word.push_str(&self.infix.concat());
+            word.push_str(&after.clone().map(|(letter, _)| letter.as_str()).collect::<String>());
+
+            let blanks = before.map(|(_, blank)| *blank).enumerate()
+                .chain(after.enumerate().map(|(index, (_, blank))| (start + self.infix.len() + index, *blank)))
+                .filter(|(_, blank)| *blank)
+                .map(|(position, _)| position)
+                .collect();
+
+            self.found.push(Placement { word, start, blanks });
+        }
+
+        for (letter, child) in edges {
+            let mut rack = rack.to_owned();
+            if let Some(blank) = rack.take(&letter) {
+                right.push((letter, blank));
+                self.grow_right(&child, left, right, rack);
+                right.pop();
+            }
+        }
+    }
+}
+
+impl Dawg {
+    /// Builds the gaddag of the lexicon, so that [`Dawg::placements`] and [`Dawg::extend_with`] start from the infix instead of going through the whole lexicon
+    /// Call this once the dawg is closed (after [`Dawg::finish`]), the gaddag takes several times the memory of the dawg itself
+    pub fn build_gaddag(&mut self) {
+        self.gaddag = Some(Box::new(Gaddag::new(self)));
+    }
+
+    /// Returns every way of forming a word around `infix` (e.g. letters already on a board) with (some or all of) the letters of `rack`,
+    /// where [`BLANK`](crate::BLANK) ("?") stands for any letter. Placements are sorted by word (in the order of the dawg) then by where the infix is in the word.
+    /// This is a walk down the gaddag when it has been built with [`Dawg::build_gaddag`], otherwise every word in the lexicon is checked
+    ///
+    /// ```rust
+    /// use dawg::{Dawg, Placement};
+    ///
+    /// let mut words = vec!["CAT", "CATS", "SCAT", "ACT", "TACT"];
+    /// words.sort();
+    ///
+    /// let mut lexicon = Dawg::new();
+    /// for word in words {
+    ///     lexicon.insert(word.to_string());
+    /// }
+    /// lexicon.finish();
+    /// lexicon.build_gaddag();
+    ///
+    /// let placements = lexicon.placements("CT", "A?");
+    /// assert_eq!(placements, vec![
+    ///     Placement { word: "ACT".to_string(), start: 1, blanks: vec![] },
+    ///     Placement { word: "TACT".to_string(), start: 2, blanks: vec![0] },
+    /// ]);
+    /// ```
+    pub fn placements(&self, infix: impl AsRef<str>, rack: impl AsRef<str>) -> Vec<Placement> {
+        let infix = self.tokenize(infix);
+        let rack = self.tokenize(rack);
+
+        let placements = match (&self.gaddag, infix.is_empty()) {
+            (Some(gaddag), false) => gaddag.placements(&infix, Rack::new(rack)),
+            _ => self.scan_placements(&infix, rack),
+        };
+
+        let mut placements = placements.into_iter().map(|placement| (self.tokenize(&placement.word), placement)).collect::<Vec<_>>();
+        placements.sort_by(|a, b| self.compare_sequences(&a.0, &b.0).then(a.1.start.cmp(&b.1.start)));
+        placements.into_iter().map(|(_, placement)| placement).collect()
+    }
+
+    /// The placements of `infix`, checking every word that could be spelled with the letters of `rack` and `infix`
+    /// (letters are taken from the rack in the same order as a walk down the gaddag, so that blanks end up in the same places)
+    fn scan_placements(&self, infix: &[String], rack: Vec<String>) -> Vec<Placement> {
+        let candidates = Anagrams::new(self, [rack.as_slice(), infix].concat(), false, infix.to_vec());
+        let rack = Rack::new(rack);
+        let mut placements = vec![];
+
+        for word in candidates {
+            let letters = self.tokenize(&word);
+
+            for start in 0..=letters.len().saturating_sub(infix.len()) {
+                if letters[start..].len() < infix.len() || letters[start..start + infix.len()] != *infix {
+                    continue;
+                }
+
+                let mut rack = rack.to_owned();
+                let mut blanks = vec![];
+                let outside = (0..start).rev().chain(start + infix.len()..letters.len());
+
+                let placed = outside.into_iter().all(|position| match rack.take(&letters[position]) {
+                    Some(blank) => {
+                        if blank {
+                            blanks.push(position);
+                        }
+                        true
+                    }
+                    None => false,
+                });
+
+                if placed {
+                    blanks.sort_unstable();
+                    placements.push(Placement { word: word.to_owned(), start, blanks });
+                }
+
+                // an empty infix is placed once, at the start of the word
+                if infix.is_empty() {
+                    break;
+                }
+            }
+        }
+
+        placements
+    }
+}
@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod test_gaddag {
+    use crate::dawg::dawg::Dawg;
+    use crate::dawg::gaddag::Placement;
+    use crate::dawg::symbol::Tiles;
+
+    fn setup_dawg() -> Dawg {
+        let mut words = include_str!("../sample_words.txt").lines()
+            .chain(["BANANA", "CABANA", "ÒYÀ", "AYÒ"])
+            .map(|word| word.to_string())
+            .collect::<Vec<_>>();
+        words.sort();
+
+        let mut dawg = Dawg::new();
+        for word in words {
+            dawg.insert(word);
+        }
+        dawg.finish();
+        dawg
+    }
+
+    fn placement(word: &str, start: usize, blanks: Vec<usize>) -> Placement {
+        Placement { word: word.to_string(), start, blanks }
+    }
+
+    #[test]
+    fn should_place_words_around_an_infix() {
+        let mut dawg = setup_dawg();
+        dawg.build_gaddag();
+
+        assert_eq!(dawg.placements("END", "SC"), vec![placement("END", 0, vec![]), placement("SCEND", 2, vec![])]);
+        assert_eq!(dawg.placements("ICK", "P"), vec![placement("PICK", 1, vec![])]);
+        // AYÒ would need an "A", the rack has an "À"
+        assert_eq!(dawg.placements("Ò", "YÀ"), vec![placement("ÒYÀ", 0, vec![])]);
+        assert!(dawg.placements("QZ", "ABCDEFG").is_empty());
+    }
+
+    #[test]
+    fn should_report_every_place_the_infix_fits() {
+        let mut dawg = setup_dawg();
+        dawg.build_gaddag();
+
+        // "ANA" is twice in BANANA and in CABANA, each placement needs different letters from the rack
+        assert_eq!(dawg.placements("ANA", "BNA"), vec![placement("BANANA", 1, vec![]), placement("BANANA", 3, vec![])]);
+        assert_eq!(dawg.placements("ANA", "BNAC"), vec![
+            placement("BANANA", 1, vec![]),
+            placement("BANANA", 3, vec![]),
+            placement("CABANA", 3, vec![]),
+        ]);
+    }
+
+    #[test]
+    fn should_use_blanks_for_missing_letters() {
+        let mut dawg = setup_dawg();
+        dawg.build_gaddag();
+
+        assert_eq!(dawg.placements("ICK", "?"), vec![placement("PICK", 1, vec![0])]);
+        // a letter in the rack is used before a blank
+        assert_eq!(dawg.placements("ICK", "P?"), vec![placement("PICK", 1, vec![]), placement("PICKY", 1, vec![4])]);
+        assert_eq!(dawg.placements("ICK", "??"), vec![placement("PICK", 1, vec![0]), placement("PICKY", 1, vec![0, 4])]);
+    }
+
+    #[test]
+    fn should_return_the_same_placements_without_the_gaddag() {
+        let mut dawg = setup_dawg();
+        let queries = [("END", "SCUPS"), ("ICK", "P??"), ("A", "NAB"), ("ANA", "BNAC?"), ("Ò", "AY"), ("EE", "ABC"), ("", "DOG")];
+
+        let without = queries.iter().map(|(infix, rack)| dawg.placements(infix, rack)).collect::<Vec<_>>();
+        let extended = queries.iter().map(|(infix, rack)| dawg.extend_with(infix, rack).collect::<Vec<_>>()).collect::<Vec<_>>();
+        dawg.build_gaddag();
+
+        for (index, (infix, rack)) in queries.iter().enumerate() {
+            assert_eq!(dawg.placements(infix, rack), without[index]);
+            assert_eq!(dawg.extend_with(infix, rack).collect::<Vec<_>>(), extended[index]);
+        }
+    }
+
+    #[test]
+    fn should_extend_an_infix_into_every_word_once() {
+        let mut dawg = setup_dawg();
+        dawg.build_gaddag();
+
+        assert_eq!(dawg.extend_with("ANA", "BNAC").collect::<Vec<_>>(), vec!["BANANA", "CABANA"]);
+        // SPICKS would need a second "S"
+        assert_eq!(dawg.extend_with("PIC", "KEDYS").collect::<Vec<_>>(), vec!["EPIC", "PIC", "PICK", "PICKED", "PICKY", "SPIC"]);
+    }
+
+    #[test]
+    fn should_match_the_infix_on_whole_tiles() {
+        let mut words = ["CALLE", "LACE", "LLAVE"].map(String::from);
+        let mut dawg = Dawg::with_tokenizer(Tiles::new(["LL"]));
+        dawg.sort_words(&mut words);
+        for word in words {
+            dawg.insert(word);
+        }
+        dawg.finish();
+
+        // the "LL" of "CALLE" is a single tile, which does not contain the tile "L"
+        assert_eq!(dawg.extend_with("L", "CALLE").collect::<Vec<_>>(), vec!["LACE"]);
+        assert_eq!(dawg.extend_with("LL", "CAEL").collect::<Vec<_>>(), vec!["CALLE"]);
+
+        dawg.build_gaddag();
+        assert_eq!(dawg.extend_with("L", "CALLE").collect::<Vec<_>>(), vec!["LACE"]);
+        assert_eq!(dawg.extend_with("LL", "CAEL").collect::<Vec<_>>(), vec!["CALLE"]);
+    }
+}
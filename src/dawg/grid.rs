@@ -0,0 +1,183 @@
+use std::collections::HashSet;
+
+#[cfg(not(feature = "threading"))]
+use std::rc::Rc;
+#[cfg(feature = "threading")]
+use std::sync::Arc;
+
+use crate::dawg::dawg::Dawg;
+use crate::node::node::Node;
+
+#[cfg(test)]
+#[path = "./grid.test.rs"]
+mod grid_test;
+
+/// Rules used by [`Dawg::solve_grid`] when walking a letter grid (Boggle style)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GridRules {
+    /// The least number of letters (see [`Dawg::tokenize`]) a word must have to be returned
+    /// A multi-letter tile like "QU" counts as two letters, unless the dawg's tokenizer makes it a single one
+    pub min_length: usize,
+    /// TRUE: a tile is adjacent to the (up to) 8 tiles around it
+    /// FALSE: only tiles above, below, left and right of it are adjacent
+    pub diagonals: bool,
+    /// Whether the edges of the grid wrap around (the last column is adjacent to the first one, same for rows)
+    pub wraparound: bool,
+}
+
+impl Default for GridRules {
+    /// The classic Boggle rules: words of 3 letters or more, diagonal moves allowed and no wraparound
+    fn default() -> Self {
+        Self { min_length: 3, diagonals: true, wraparound: false }
+    }
+}
+
+/// A grid being solved, tiles are kept already split into the letters of the dawg
+struct Board<'a> {
+    tiles: Vec<Vec<Vec<String>>>,
+    rules: &'a GridRules,
+}
+
+impl<'a> Board<'a> {
+    /// Returns the (distinct) tiles a path can move to from the tile at `row`, `col`
+    fn neighbours(&self, row: usize, col: usize) -> Vec<(usize, usize)> {
+        let mut result = vec![];
+        let rows = self.tiles.len() as isize;
+
+        for d_row in -1isize..=1 {
+            for d_col in -1isize..=1 {
+                if (d_row == 0 && d_col == 0) || (!self.rules.diagonals && d_row != 0 && d_col != 0) {
+                    continue;
+                }
+
+                let mut next_row = row as isize + d_row;
+                if self.rules.wraparound {
+                    next_row = next_row.rem_euclid(rows);
+                } else if next_row < 0 || next_row >= rows {
+                    continue;
+                }
+
+                let cols = self.tiles[next_row as usize].len() as isize;
+                let mut next_col = col as isize + d_col;
+                if self.rules.wraparound && cols > 0 {
+                    next_col = next_col.rem_euclid(cols);
+                } else if next_col < 0 || next_col >= cols {
+                    continue;
+                }
+
+                let next = (next_row as usize, next_col as usize);
+                if next != (row, col) && !result.contains(&next) {
+                    result.push(next);
+                }
+            }
+        }
+
+        result
+    }
+
+    fn visit(&self, node: &Node, position: (usize, usize), path: &mut Path, found: &mut HashSet<String>) {
+        let (row, col) = position;
+        let tile = &self.tiles[row][col];
+
+        #[cfg(not(feature = "threading"))]
+        let mut node = Rc::clone(node);
+        #[cfg(feature = "threading")]
+        let mut node = Arc::clone(node);
+
+        // a multi-letter tile has to be matched letter by letter
+        for letter in tile {
+            #[cfg(not(feature = "threading"))]
+            let next = node.borrow().edges.get(letter).map(Rc::clone);
+            #[cfg(feature = "threading")]
+            let next = node.lock().unwrap().edges.get(letter).map(Arc::clone);
+
+            match next {
+                Some(next) => node = next,
+                None => return,
+            }
+        }
+
+        let word_length = path.word.len();
+        path.word.push_str(&tile.concat());
+        path.letters += tile.len();
+        path.visited[row][col] = true;
+
+        #[cfg(not(feature = "threading"))]
+        let is_terminal = node.borrow().terminal;
+        #[cfg(feature = "threading")]
+        let is_terminal = node.lock().unwrap().terminal;
+
+        if is_terminal && path.letters >= self.rules.min_length {
+            found.insert(path.word.to_owned());
+        }
+
+        for (next_row, next_col) in self.neighbours(row, col) {
+            if !path.visited[next_row][next_col] {
+                self.visit(&node, (next_row, next_col), path, found);
+            }
+        }
+
+        path.visited[row][col] = false;
+        path.letters -= tile.len();
+        path.word.truncate(word_length);
+    }
+}
+
+/// The word formed so far while walking the grid
+struct Path {
+    word: String,
+    letters: usize,
+    visited: Vec<Vec<bool>>,
+}
+
+impl Dawg {
+    /// Returns all the words in the lexicon that can be formed on a letter grid (Boggle),
+    /// by moving from a tile to an adjacent one without using a tile more than once
+    /// The search only follows paths that are prefixes of words in the dawg,
+    /// tiles can hold more than one letter e.g. "QU", and results are returned in the order of the dawg
+    ///
+    /// ```rust
+    /// use dawg::{Dawg, GridRules};
+    ///
+    /// let mut words = vec!["QUIT", "QUITE", "TIE", "TEA", "SUIT", "ZZZ"];
+    /// words.sort();
+    ///
+    /// let mut lexicon = Dawg::new();
+    /// for word in words {
+    ///     lexicon.insert(word.to_string());
+    /// }
+    /// lexicon.finish();
+    ///
+    /// let grid = vec![
+    ///     vec!["QU", "I"],
+    ///     vec!["E", "T"],
+    /// ];
+    ///
+    /// let result = lexicon.solve_grid(&grid, &GridRules::default());
+    /// assert_eq!(result, vec!["QUIT", "QUITE", "TIE"]);
+    /// ```
+    pub fn solve_grid<T: AsRef<str>>(&self, grid: &[Vec<T>], rules: &GridRules) -> Vec<String> {
+        let tiles = grid.iter()
+            .map(|row| row.iter().map(|tile| self.tokenize(tile)).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        let board = Board { tiles, rules };
+        let mut path = Path {
+            word: String::new(),
+            letters: 0,
+            visited: grid.iter().map(|row| vec![false; row.len()]).collect(),
+        };
+        let mut found = HashSet::new();
+        let root = self.get_root();
+
+        for (row, tiles) in grid.iter().enumerate() {
+            for col in 0..tiles.len() {
+                board.visit(&root, (row, col), &mut path, &mut found);
+            }
+        }
+
+        let mut found = found.into_iter().collect::<Vec<_>>();
+        self.sort_words(&mut found);
+        found
+    }
+}
@@ -0,0 +1,121 @@
+#[cfg(test)]
+mod test_grid {
+    use crate::dawg::collation::Alphabet;
+    use crate::dawg::dawg::Dawg;
+    use crate::dawg::grid::GridRules;
+    use crate::dawg::symbol::Tiles;
+
+    fn setup_dawg(words: &[&str]) -> Dawg {
+        let mut words = words.iter().map(|x| x.to_string()).collect::<Vec<_>>();
+        words.sort();
+
+        let mut dawg = Dawg::new();
+        for word in words {
+            dawg.insert(word);
+        }
+        dawg.finish();
+        dawg
+    }
+
+    #[test]
+    fn should_find_words_formed_by_adjacent_tiles() {
+        let dawg = setup_dawg(&["CAT", "ACT", "TAC", "CAB", "BAT", "TAB", "ABACA", "AT"]);
+        let grid = vec![
+            vec!["C", "A"],
+            vec!["B", "T"],
+        ];
+
+        let received = dawg.solve_grid(&grid, &GridRules::default());
+        assert_eq!(received, vec!["ACT", "BAT", "CAB", "CAT", "TAB", "TAC"]);
+    }
+
+    #[test]
+    fn should_respect_the_minimum_length() {
+        let dawg = setup_dawg(&["CAT", "AT", "A"]);
+        let grid = vec![vec!["C", "A", "T"]];
+
+        let rules = GridRules { min_length: 1, ..GridRules::default() };
+        assert_eq!(dawg.solve_grid(&grid, &rules), vec!["A", "AT", "CAT"]);
+
+        let rules = GridRules { min_length: 2, ..GridRules::default() };
+        assert_eq!(dawg.solve_grid(&grid, &rules), vec!["AT", "CAT"]);
+    }
+
+    #[test]
+    fn should_only_move_diagonally_when_allowed() {
+        let dawg = setup_dawg(&["CAT", "CTA"]);
+        let grid = vec![
+            vec!["C", "X"],
+            vec!["Y", "A"],
+            vec!["T", "Z"],
+        ];
+
+        // C -> A and A -> T are diagonal moves
+        assert_eq!(dawg.solve_grid(&grid, &GridRules::default()), vec!["CAT"]);
+        let rules = GridRules { diagonals: false, ..GridRules::default() };
+        assert!(dawg.solve_grid(&grid, &rules).is_empty());
+    }
+
+    #[test]
+    fn should_wrap_around_the_edges_when_allowed() {
+        let dawg = setup_dawg(&["TAC", "CAT"]);
+        let grid = vec![vec!["A", "X", "C"], vec!["X", "X", "X"], vec!["T", "X", "X"]];
+
+        let rules = GridRules { diagonals: false, ..GridRules::default() };
+        assert!(dawg.solve_grid(&grid, &rules).is_empty());
+
+        // "C" wraps to "A" (same row), "A" wraps to "T" (same column)
+        let rules = GridRules { diagonals: false, wraparound: true, ..GridRules::default() };
+        assert_eq!(dawg.solve_grid(&grid, &rules), vec!["CAT", "TAC"]);
+    }
+
+    #[test]
+    fn should_not_reuse_a_tile() {
+        let dawg = setup_dawg(&["NOON", "NON", "ON"]);
+        let grid = vec![vec!["N", "O"], vec!["X", "X"]];
+
+        let rules = GridRules { min_length: 2, ..GridRules::default() };
+        assert_eq!(dawg.solve_grid(&grid, &rules), vec!["ON"]);
+    }
+
+    #[test]
+    fn should_support_multi_letter_and_accented_tiles() {
+        let dawg = setup_dawg(&["QUIT", "QIT", "ÒYÀ", "AYÒ"]);
+        let grid = vec![vec!["QU", "I", "T"], vec!["Ò", "Y", "À"]];
+
+        assert_eq!(dawg.solve_grid(&grid, &GridRules::default()), vec!["QUIT", "ÒYÀ"]);
+    }
+
+    #[test]
+    fn should_match_tiles_with_the_letters_of_the_dawg() {
+        let mut dawg = Dawg::with_tokenizer(Tiles::new(["LL"]));
+        let mut words = ["LLAMA", "LAMA", "MALL"].map(String::from);
+        dawg.sort_words(&mut words);
+        for word in words {
+            dawg.insert(word);
+        }
+        dawg.finish();
+
+        // "LL" is a single letter of the dawg, so a "LL" tile makes it but two "L" tiles don't
+        let grid = vec![vec!["LL", "A"], vec!["M", "A"]];
+        assert_eq!(dawg.solve_grid(&grid, &GridRules::default()), vec!["LLAMA", "MALL"]);
+
+        let grid = vec![vec!["L", "L", "A"], vec!["X", "M", "A"]];
+        assert_eq!(dawg.solve_grid(&grid, &GridRules::default()), vec!["LAMA"]);
+    }
+
+    #[test]
+    fn should_return_the_words_in_the_order_of_the_collator() {
+        let mut dawg = Dawg::with_collator(Alphabet::spanish());
+        let mut words = ["ZU", "ÑU", "NU"].map(String::from);
+        dawg.sort_words(&mut words);
+        for word in words {
+            dawg.insert(word);
+        }
+        dawg.finish();
+
+        let grid = vec![vec!["Ñ", "U"], vec!["N", "Z"]];
+        let rules = GridRules { min_length: 2, ..GridRules::default() };
+        assert_eq!(dawg.solve_grid(&grid, &rules), vec!["NU", "ÑU", "ZU"]);
+    }
+}
@@ -0,0 +1,397 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use crate::dawg::dawg::Dawg;
+
+#[cfg(test)]
+#[path = "./hunspell.test.rs"]
+mod hunspell_test;
+
+/// How the flags of a dictionary are written (the `FLAG` line of the .aff file)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum FlagType {
+    /// one character per flag (the default)
+    #[default]
+    Short,
+    /// two characters per flag (`FLAG long`)
+    Long,
+    /// numbers separated by commas (`FLAG num`)
+    Numeric,
+}
+
+/// A letter of the condition of an affix rule
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Condition {
+    /// `.`
+    Any,
+    /// `a` or `[abc]`
+    OneOf(Vec<char>),
+    /// `[^abc]`
+    NoneOf(Vec<char>),
+}
+
+impl Condition {
+    /// Parses a condition such as "[^aeiou]y" into its letters ("." matches any word)
+    fn parse(condition: &str) -> Vec<Self> {
+        let mut letters = vec![];
+        let mut chars = condition.chars();
+
+        while let Some(letter) = chars.next() {
+            letters.push(match letter {
+                '.' => Self::Any,
+                '[' => {
+                    let set = chars.by_ref().take_while(|letter| *letter != ']').collect::<Vec<_>>();
+                    match set.split_first() {
+                        Some(('^', rest)) => Self::NoneOf(rest.to_vec()),
+                        _ => Self::OneOf(set),
+                    }
+                }
+                letter => Self::OneOf(vec![letter]),
+            });
+        }
+
+        if letters == [Self::Any] { vec![] } else { letters }
+    }
+
+    fn matches(&self, letter: char) -> bool {
+        match self {
+            Self::Any => true,
+            Self::OneOf(set) => set.contains(&letter),
+            Self::NoneOf(set) => !set.contains(&letter),
+        }
+    }
+}
+
+/// A line of a `PFX` or `SFX` class, e.g. `SFX D y ied [^aeiou]y` (cry -> cried)
+#[derive(Debug, Clone)]
+struct AffixRule {
+    strip: String,
+    add: String,
+    /// the flags of the affixes that may follow this one (twofold suffixes, `SFX D y ied/S ...`)
+    continuation: Vec<String>,
+    condition: Vec<Condition>,
+}
+
+impl AffixRule {
+    /// Returns `word` with this suffix, if the end of the word meets the condition
+    fn suffix(&self, word: &str) -> Option<String> {
+        let letters = word.chars().collect::<Vec<_>>();
+        let strip = self.strip.chars().count();
+        let end = letters.len().checked_sub(self.condition.len())?;
+
+        let meets = letters[end..].iter().zip(&self.condition).all(|(letter, condition)| condition.matches(*letter));
+        if !meets || strip >= letters.len() || !word.ends_with(&self.strip) {
+            return None;
+        }
+
+        Some(format!("{}{}", &word[..word.len() - self.strip.len()], self.add))
+    }
+
+    /// Returns `word` with this prefix, if the start of the word meets the condition
+    fn prefix(&self, word: &str) -> Option<String> {
+        let letters = word.chars().collect::<Vec<_>>();
+        let strip = self.strip.chars().count();
+
+        let meets = letters.len() >= self.condition.len() && letters.iter().zip(&self.condition).all(|(letter, condition)| condition.matches(*letter));
+        if !meets || strip >= letters.len() || !word.starts_with(&self.strip) {
+            return None;
+        }
+
+        Some(format!("{}{}", self.add, &word[self.strip.len()..]))
+    }
+}
+
+/// The rules of a `PFX` or `SFX` flag
+#[derive(Debug, Clone, Default)]
+struct AffixClass {
+    /// whether the prefixes of this class combine with the suffixes (and the other way round), the `Y` of `SFX D Y 4`
+    cross: bool,
+    rules: Vec<AffixRule>,
+}
+
+/// A word of a Hunspell dictionary expanded with its affixes, see [`Hunspell::tagged_words`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HunspellWord {
+    pub word: String,
+    /// the flags of the dictionary words it comes from (e.g. their part of speech, depending on the dictionary)
+    pub flags: Vec<String>,
+}
+
+/// A Hunspell dictionary: the words of a .dic file and the prefix and suffix rules of its .aff file.
+///
+/// The usual rules are supported: the `FLAG` types (single characters, `long` and `num`), flag aliases (`AF`), affix conditions,
+/// cross products of prefixes and suffixes, twofold suffixes, and the `NEEDAFFIX`, `FORBIDDENWORD` and `ONLYINCOMPOUND` flags
+/// (compounding and morphology are not). Lines that cannot be read are skipped, as Hunspell does.
+///
+/// ```rust
+/// use dawg::{Dawg, Hunspell};
+///
+/// let aff = "SET UTF-8\n\
+///     SFX S Y 2\n\
+///     SFX S 0 s [^y]\n\
+///     SFX S y ies [^aeiou]y\n\
+///     PFX U Y 1\n\
+///     PFX U 0 un .\n";
+/// let dic = "3\ncat/S\nfly/S\ntidy/U\n";
+///
+/// let dictionary = Hunspell::new(dic, aff);
+/// assert_eq!(dictionary.words(), vec!["cat", "cats", "flies", "fly", "tidy", "untidy"]);
+///
+/// let lexicon = Dawg::from_hunspell(dic, aff);
+/// assert!(lexicon.is_word("flies", true).is_some());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Hunspell {
+    flag_type: FlagType,
+    /// the flag sets of `AF`, the dictionary refers to them by number (from 1)
+    aliases: Vec<Vec<String>>,
+    /// whether the first `AF` line (the number of aliases) has been read
+    reading_aliases: bool,
+    prefixes: HashMap<String, AffixClass>,
+    suffixes: HashMap<String, AffixClass>,
+    need_affix: Option<String>,
+    forbidden: Option<String>,
+    only_in_compound: Option<String>,
+    /// the words of the .dic file with their flags
+    entries: Vec<(String, Vec<String>)>,
+}
+
+impl Hunspell {
+    /// Reads a dictionary from the contents of its .dic and .aff files (which must already be decoded into text)
+    pub fn new(dic: &str, aff: &str) -> Self {
+        let mut dictionary = Self::default();
+
+        for line in aff.lines() {
+            dictionary.read_affix_line(line);
+        }
+
+        let mut lines = dic.lines().map(str::trim).filter(|line| !line.is_empty());
+        // the first line is the number of words
+        let first = lines.next().filter(|line| line.parse::<usize>().is_err());
+
+        for line in first.into_iter().chain(lines) {
+            if line.starts_with('#') {
+                continue;
+            }
+
+            // morphological fields follow the word after a space or a tab
+            let entry = line.split_whitespace().next().unwrap_or_default();
+            let split = entry.char_indices().find(|(index, letter)| *letter == '/' && !entry[..*index].ends_with('\\'));
+            let (word, flags) = match split {
+                Some((index, _)) => (&entry[..index], dictionary.flags(&entry[index + 1..])),
+                None => (entry, vec![]),
+            };
+
+            dictionary.entries.push((word.replace("\\/", "/"), flags));
+        }
+
+        dictionary
+    }
+
+    /// Splits a string of flags (or an alias of one) into flags
+    fn flags(&self, flags: &str) -> Vec<String> {
+        if !self.aliases.is_empty() {
+            if let Ok(alias) = flags.parse::<usize>() {
+                return self.aliases.get(alias.wrapping_sub(1)).cloned().unwrap_or_default();
+            }
+        }
+
+        match self.flag_type {
+            FlagType::Short => flags.chars().map(String::from).collect(),
+            FlagType::Long => flags.chars().collect::<Vec<_>>().chunks(2).map(|flag| flag.iter().collect()).collect(),
+            FlagType::Numeric => flags.split(',').map(|flag| flag.trim().to_string()).filter(|flag| !flag.is_empty()).collect(),
+        }
+    }
+
+    fn read_affix_line(&mut self, line: &str) {
+        let fields = line.split_whitespace().collect::<Vec<_>>();
+
+        match fields.as_slice() {
+            ["FLAG", kind, ..] => {
+                self.flag_type = match *kind {
+                    "long" => FlagType::Long,
+                    "num" => FlagType::Numeric,
+                    // "UTF-8" flags are single characters, like the default ones
+                    _ => FlagType::Short,
+                };
+            }
+            // the first AF line is the number of aliases
+            ["AF", _, ..] if !self.reading_aliases => self.reading_aliases = true,
+            ["AF", flags, ..] => {
+                // aliases cannot refer to other aliases
+                let aliases = std::mem::take(&mut self.aliases);
+                let flags = self.flags(flags);
+                self.aliases = aliases;
+                self.aliases.push(flags);
+            }
+            ["NEEDAFFIX" | "PSEUDOROOT", flag, ..] => self.need_affix = Some(flag.to_string()),
+            ["FORBIDDENWORD", flag, ..] => self.forbidden = Some(flag.to_string()),
+            ["ONLYINCOMPOUND", flag, ..] => self.only_in_compound = Some(flag.to_string()),
+            [kind @ ("PFX" | "SFX"), flag, rest @ ..] => {
+                let defined = if *kind == "PFX" { self.prefixes.contains_key(*flag) } else { self.suffixes.contains_key(*flag) };
+
+                // the first line of a class is its header: `SFX flag cross count`
+                if !defined {
+                    let class = AffixClass { cross: rest.first() == Some(&"Y"), rules: vec![] };
+                    let classes = if *kind == "PFX" { &mut self.prefixes } else { &mut self.suffixes };
+                    classes.insert(flag.to_string(), class);
+                    return;
+                }
+
+                let [strip, add, condition @ ..] = rest else { return };
+                let (add, continuation) = match add.split_once('/') {
+                    Some((add, flags)) => (add, self.flags(flags)),
+                    None => (*add, vec![]),
+                };
+                let empty = |text: &str| if text == "0" { String::new() } else { text.to_string() };
+                let rule = AffixRule {
+                    strip: empty(strip),
+                    add: empty(add),
+                    continuation,
+                    condition: Condition::parse(condition.first().unwrap_or(&".")),
+                };
+
+                let classes = if *kind == "PFX" { &mut self.prefixes } else { &mut self.suffixes };
+                if let Some(class) = classes.get_mut(*flag) {
+                    class.rules.push(rule);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Returns every form of a word of the dictionary, the word itself included unless it needs an affix
+    fn expand(&self, word: &str, flags: &[String]) -> Vec<String> {
+        let has = |flag: &Option<String>| flag.as_ref().is_some_and(|flag| flags.contains(flag));
+        if has(&self.forbidden) {
+            return vec![];
+        }
+
+        let mut forms = vec![];
+        if !has(&self.need_affix) && !has(&self.only_in_compound) {
+            forms.push(word.to_string());
+        }
+
+        // the suffixed forms, and whether they may take a prefix too
+        let mut suffixed = vec![];
+        for class in flags.iter().filter_map(|flag| self.suffixes.get(flag)) {
+            for rule in &class.rules {
+                let Some(form) = rule.suffix(word) else { continue };
+
+                for next in rule.continuation.iter().filter_map(|flag| self.suffixes.get(flag)) {
+                    forms.extend(next.rules.iter().filter_map(|next| next.suffix(&form)));
+                }
+                if !self.need_affix.as_ref().is_some_and(|flag| rule.continuation.contains(flag)) {
+                    forms.push(form.to_owned());
+                }
+                suffixed.push((form, class.cross));
+            }
+        }
+
+        for class in flags.iter().filter_map(|flag| self.prefixes.get(flag)) {
+            for rule in &class.rules {
+                forms.extend(rule.prefix(word));
+
+                if class.cross {
+                    let crossed = suffixed.iter().filter(|(_, cross)| *cross).filter_map(|(form, _)| rule.prefix(form));
+                    forms.extend(crossed);
+                }
+            }
+        }
+
+        forms
+    }
+
+    /// Returns every word of the dictionary with its affixes applied, sorted and without duplicates
+    /// (the whole expanded list is held in memory)
+    pub fn words(&self) -> Vec<String> {
+        let mut words = self.entries.iter().flat_map(|(word, flags)| self.expand(word, flags)).collect::<Vec<_>>();
+        words.sort();
+        words.dedup();
+        words
+    }
+
+    /// Returns every word of the dictionary with its affixes applied (sorted and without duplicates),
+    /// tagged with the flags of the dictionary words it comes from (the whole expanded list is held in memory)
+    pub fn tagged_words(&self) -> Vec<HunspellWord> {
+        let mut words: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+
+        for (word, flags) in &self.entries {
+            for form in self.expand(word, flags) {
+                words.entry(form).or_default().extend(flags.iter().cloned());
+            }
+        }
+
+        words.into_iter().map(|(word, flags)| HunspellWord { word, flags: flags.into_iter().collect() }).collect()
+    }
+
+    /// Every word of the dictionary with its affixes applied, split into letters and sorted in the order of `dawg`,
+    /// with the flags of the dictionary word it comes from (a word coming from more than one is there more than once)
+    fn sorted_forms<'a>(&'a self, dawg: &Dawg) -> Vec<(Vec<String>, &'a [String])> {
+        let mut forms = self.entries.iter()
+            .flat_map(|(word, flags)| self.expand(word, flags).into_iter().map(move |form| (form, flags.as_slice())))
+            .map(|(form, flags)| (dawg.tokenize(form), flags))
+            .collect::<Vec<_>>();
+
+        forms.sort_by(|a, b| dawg.compare_sequences(&a.0, &b.0));
+        forms
+    }
+
+    /// Inserts every word of the dictionary (with its affixes applied) into an empty `dawg`, in the order of the dawg, and finishes it
+    /// Use this rather than [`Dawg::from_hunspell`] to keep the tokenizer, normalization or collator the dawg was set up with.
+    /// The whole expanded list of words is held in memory while it is sorted
+    pub fn insert_into(&self, dawg: &mut Dawg) {
+        let mut forms = self.sorted_forms(dawg).into_iter().map(|(letters, _)| letters).collect::<Vec<_>>();
+        forms.dedup();
+
+        for letters in forms {
+            dawg.insert_sequence(letters);
+        }
+        dawg.finish();
+    }
+
+    /// Like [`Hunspell::insert_into`], also returning the flags of every word of the dawg (sorted and without duplicates, see
+    /// [`Hunspell::tagged_words`]) indexed by its rank: the flags of a word are at [`Dawg::rank`] of the word
+    ///
+    /// ```rust
+    /// use dawg::{Dawg, Hunspell};
+    ///
+    /// let dictionary = Hunspell::new("2\ncat/S\ntidy/U\n", "SFX S Y 1\nSFX S 0 s .\nPFX U Y 1\nPFX U 0 un .\n");
+    ///
+    /// let mut lexicon = Dawg::new();
+    /// let flags = dictionary.insert_tagged_into(&mut lexicon);
+    ///
+    /// let rank = lexicon.rank("untidy").unwrap();
+    /// assert_eq!(flags[rank], vec!["U"]);
+    /// ```
+    pub fn insert_tagged_into(&self, dawg: &mut Dawg) -> Vec<Vec<String>> {
+        let mut tagged: Vec<(Vec<String>, Vec<String>)> = vec![];
+
+        for (letters, flags) in self.sorted_forms(dawg) {
+            match tagged.last_mut() {
+                Some((last, tags)) if *last == letters => tags.extend(flags.iter().cloned()),
+                _ => tagged.push((letters, flags.to_vec())),
+            }
+        }
+
+        let mut table = Vec::with_capacity(tagged.len());
+        for (letters, mut flags) in tagged {
+            flags.sort();
+            flags.dedup();
+
+            dawg.insert_sequence(letters);
+            table.push(flags);
+        }
+        dawg.finish();
+
+        table
+    }
+}
+
+impl Dawg {
+    /// Builds a (finished) dawg of every word of a Hunspell dictionary with its affixes applied, from the contents of its .dic and .aff files (see [`Hunspell`])
+    pub fn from_hunspell(dic: &str, aff: &str) -> Self {
+        let mut dawg = Self::new();
+        Hunspell::new(dic, aff).insert_into(&mut dawg);
+        dawg
+    }
+}
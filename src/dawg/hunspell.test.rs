@@ -0,0 +1,137 @@
+#[cfg(test)]
+mod test_hunspell {
+    use crate::dawg::collation::Alphabet;
+    use crate::dawg::dawg::Dawg;
+    use crate::dawg::hunspell::{Hunspell, HunspellWord};
+
+    const AFF: &str = "
+# an English-like affix file
+SET UTF-8
+TRY esianrtolcdugmphbyfvkwz
+
+NEEDAFFIX X
+FORBIDDENWORD F
+
+PFX A Y 1
+PFX A 0 re .
+
+PFX I N 1
+PFX I 0 in .
+
+SFX D Y 4
+SFX D 0 d e
+SFX D y ied [^aeiou]y
+SFX D 0 ed [^ey]
+SFX D 0 ed [aeiou]y
+
+SFX S Y 3
+SFX S y ies [^aeiou]y
+SFX S 0 s [aeiou]y
+SFX S 0 s [^sy]
+
+SFX N N 1
+SFX N e ion/S e
+";
+
+    const DIC: &str = "6
+cry/DS
+play/ADS
+create/ADN
+active/I
+bizarre/XS
+plaies/F
+";
+
+    #[test]
+    fn should_apply_suffixes_with_their_conditions() {
+        let dictionary = Hunspell::new("2\ncry/DS\nplay/DS\n", AFF);
+
+        assert_eq!(dictionary.words(), vec!["cried", "cries", "cry", "play", "played", "plays"]);
+    }
+
+    #[test]
+    fn should_apply_prefixes_and_their_cross_products() {
+        let words = Hunspell::new(DIC, AFF).words();
+
+        for word in ["replay", "replayed", "replays", "recreate", "recreated", "inactive", "creation", "creations"] {
+            assert!(words.contains(&word.to_string()), "{word} is missing");
+        }
+        // the N suffix does not combine with prefixes
+        assert!(!words.contains(&"recreation".to_string()));
+        // an I prefix does not combine with suffixes, and "active" has none
+        assert!(!words.contains(&"inactives".to_string()));
+    }
+
+    #[test]
+    fn should_follow_the_special_flags() {
+        let words = Hunspell::new(DIC, AFF).words();
+
+        // "bizarre" needs an affix, "plaies" is forbidden
+        assert!(!words.contains(&"bizarre".to_string()));
+        assert!(words.contains(&"bizarres".to_string()));
+        assert!(!words.contains(&"plaies".to_string()));
+
+        let mut sorted = words.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(words, sorted);
+    }
+
+    #[test]
+    fn should_read_every_flag_type_and_aliases() {
+        let aff = "FLAG long\nSFX Aa Y 1\nSFX Aa 0 s .\nPFX Bb Y 1\nPFX Bb 0 un .\n";
+        assert_eq!(Hunspell::new("1\ndo/AaBb\n", aff).words(), vec!["do", "dos", "undo", "undos"]);
+
+        let aff = "FLAG num\nSFX 101 Y 1\nSFX 101 0 s .\nPFX 7 Y 1\nPFX 7 0 un .\n";
+        assert_eq!(Hunspell::new("1\ndo/7,101\n", aff).words(), vec!["do", "dos", "undo", "undos"]);
+
+        let aff = "AF 2\nAF S\nAF SU # both\nSFX S Y 1\nSFX S 0 s .\nPFX U Y 1\nPFX U 0 un .\n";
+        assert_eq!(Hunspell::new("2\ndo/2\ncat/1 po:noun\n", aff).words(), vec!["cat", "cats", "do", "dos", "undo", "undos"]);
+    }
+
+    #[test]
+    fn should_keep_the_flags_as_tags() {
+        let dictionary = Hunspell::new("2\ncry/DS\nbizarre/XS\n", AFF);
+        let tagged = dictionary.tagged_words();
+        let tag = |word: &str, flags: &[&str]| HunspellWord { word: word.to_string(), flags: flags.iter().map(|flag| flag.to_string()).collect() };
+
+        assert_eq!(tagged, vec![
+            tag("bizarres", &["S", "X"]),
+            tag("cried", &["D", "S"]),
+            tag("cries", &["D", "S"]),
+            tag("cry", &["D", "S"]),
+        ]);
+    }
+
+    #[test]
+    fn should_build_a_dawg_in_its_order() {
+        let dawg = Dawg::from_hunspell(DIC, AFF);
+        assert!(dawg.is_word("recreated", true).is_some());
+        assert!(dawg.is_word("bizarre", true).is_none());
+        assert_eq!(dawg.words().collect::<Vec<_>>(), Hunspell::new(DIC, AFF).words());
+
+        // small letters sort with their capitals in this alphabet
+        let mut dawg = Dawg::with_collator(Alphabet::new(('A'..='Z').map(String::from)));
+        Hunspell::new("2\nZebra/S\napple/S\n", "SFX S Y 1\nSFX S 0 s .\n").insert_into(&mut dawg);
+        assert_eq!(dawg.words().collect::<Vec<_>>(), vec!["apple", "apples", "Zebra", "Zebras"]);
+    }
+
+    #[test]
+    fn should_build_a_dawg_with_the_flags_of_its_words() {
+        let dictionary = Hunspell::new("2\ncry/DS\nbizarre/XS\n", AFF);
+
+        let mut dawg = Dawg::new();
+        let flags = dictionary.insert_tagged_into(&mut dawg);
+
+        assert_eq!(flags.len(), dawg.words().count());
+        for tagged in dictionary.tagged_words() {
+            assert_eq!(flags[dawg.rank(&tagged.word).unwrap()], tagged.flags);
+        }
+
+        // the flags follow the order of the dawg
+        let mut dawg = Dawg::with_collator(Alphabet::new(('A'..='Z').map(String::from)));
+        let flags = Hunspell::new("2\nZebra/S\napple/T\n", "SFX S Y 1\nSFX S 0 s .\n").insert_tagged_into(&mut dawg);
+        assert_eq!(dawg.words().collect::<Vec<_>>(), vec!["apple", "Zebra", "Zebras"]);
+        assert_eq!(flags, vec![vec!["T"], vec!["S"], vec!["S"]]);
+    }
+}
@@ -0,0 +1,102 @@
+use serde::{Deserialize, Serialize};
+
+use crate::dawg::dawg::Dawg;
+
+#[cfg(test)]
+#[path = "./infix.test.rs"]
+mod infix_test;
+
+/// An index of every suffix of every word in a lexicon, used to find the words containing a substring (see [`Dawg::containing`])
+///
+/// The suffixes are stored in a dawg of their own, and since a dawg numbers its words in alphabetical order ([`Dawg::rank`]),
+/// the words a suffix comes from (their ranks in the lexicon) are kept in a `Vec` at the suffix's rank.
+/// The suffixes starting with an infix take a contiguous range of ranks, so a lookup is a single walk down the suffix dawg
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct InfixIndex {
+    suffixes: Dawg,
+    /// for every suffix (by rank), the ranks of the words ending with it
+    words: Vec<Vec<usize>>,
+}
+
+impl InfixIndex {
+    /// Builds the index of a closed (finished) dawg, the suffixes are cut between the letters of the dawg (see [`Dawg::tokenize`])
+    pub fn new(dawg: &Dawg) -> Self {
+        let mut suffixes = dawg.sequences().enumerate().flat_map(|(rank, letters)| {
+            (0..letters.len()).map(move |start| (letters[start..].to_vec(), rank))
+        }).collect::<Vec<_>>();
+
+        suffixes.sort();
+
+        let mut index = Self::default();
+        let mut previous = None;
+
+        for (suffix, rank) in suffixes {
+            if previous.as_ref() != Some(&suffix) {
+                index.suffixes.insert_sequence(suffix.to_owned());
+                index.words.push(vec![]);
+                previous = Some(suffix);
+            }
+
+            let words = index.words.last_mut().unwrap();
+            // a word with a repeated suffix (e.g. "ANANA" in "BANANA") would otherwise be listed twice
+            if words.last() != Some(&rank) {
+                words.push(rank);
+            }
+        }
+
+        index.suffixes.finish();
+        index
+    }
+
+    /// Returns the ranks (in the indexed lexicon) of the words containing the letters `infix`, in increasing order
+    /// The infix is expected to be split like the words of the indexed dawg, see [`Dawg::tokenize`]
+    pub fn ranks(&self, infix: &[String]) -> Vec<usize> {
+        let Some((start, count)) = self.suffixes.prefix_range_sequence(infix) else {
+            return vec![];
+        };
+
+        let mut ranks = self.words[start..start + count].concat();
+        ranks.sort_unstable();
+        ranks.dedup();
+        ranks
+    }
+}
+
+impl Dawg {
+    /// Indexes every suffix of every word, so that [`Dawg::containing`] doesn't have to go through the whole lexicon
+    /// Call this once the dawg is closed (after [`Dawg::finish`]), the index takes more memory than the dawg itself
+    pub fn build_infix_index(&mut self) {
+        self.infixes = Some(Box::new(InfixIndex::new(self)));
+    }
+
+    /// Returns (alphabetically) all the words that contain `infix`, as a run of whole letters (see [`Dawg::tokenize`])
+    /// This is a lookup in the suffix index when it has been built with [`Dawg::build_infix_index`],
+    /// otherwise every word in the lexicon is checked
+    ///
+    /// ```rust
+    /// use dawg::Dawg;
+    ///
+    /// let mut words = vec!["BANANA", "CABANA", "NAB", "NOTE", "ANT"];
+    /// words.sort();
+    ///
+    /// let mut lexicon = Dawg::new();
+    /// for word in words {
+    ///     lexicon.insert(word.to_string());
+    /// }
+    /// lexicon.finish();
+    /// lexicon.build_infix_index();
+    ///
+    /// assert_eq!(lexicon.containing("NA"), vec!["BANANA", "CABANA", "NAB"]);
+    /// ```
+    pub fn containing(&self, infix: impl AsRef<str>) -> Vec<String> {
+        let infix = self.tokenize(infix);
+
+        match &self.infixes {
+            Some(index) => index.ranks(&infix).into_iter().filter_map(|rank| self.word_at(rank)).collect(),
+            None => self.sequences()
+                .filter(|letters| infix.is_empty() || letters.windows(infix.len()).any(|window| window == infix))
+                .map(|letters| letters.concat())
+                .collect(),
+        }
+    }
+}
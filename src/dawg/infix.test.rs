@@ -0,0 +1,79 @@
+#[cfg(test)]
+mod test_infix {
+    use crate::dawg::dawg::Dawg;
+    use crate::dawg::infix::InfixIndex;
+    use crate::dawg::symbol::Tiles;
+
+    fn setup_dawg() -> Dawg {
+        let mut words = vec![
+            "BANANA", "CABANA", "NAB", "NOTE", "ANT", "ANTENNA", "LISTEN", "SILENT", "TENT", "ÒYÀ", "AYÒ",
+        ];
+        words.sort();
+
+        let mut dawg = Dawg::new();
+        for word in words {
+            dawg.insert(word.to_string());
+        }
+        dawg.finish();
+        dawg
+    }
+
+    #[test]
+    fn should_return_the_words_containing_an_infix() {
+        let mut dawg = setup_dawg();
+        dawg.build_infix_index();
+
+        assert_eq!(dawg.containing("NA"), vec!["ANTENNA", "BANANA", "CABANA", "NAB"]);
+        assert_eq!(dawg.containing("TEN"), vec!["ANTENNA", "LISTEN", "TENT"]);
+        assert_eq!(dawg.containing("ENT"), vec!["SILENT", "TENT"]);
+        assert_eq!(dawg.containing("Ò"), vec!["AYÒ", "ÒYÀ"]);
+        assert_eq!(dawg.containing("BANANA"), vec!["BANANA"]);
+        assert!(dawg.containing("XYZ").is_empty());
+        assert_eq!(dawg.containing("").len(), 11);
+    }
+
+    #[test]
+    fn should_return_the_same_words_without_the_index() {
+        let mut dawg = setup_dawg();
+        let infixes = ["NA", "TEN", "A", "Ò", "SILENT", "Q", ""];
+
+        let without = infixes.iter().map(|infix| dawg.containing(infix)).collect::<Vec<_>>();
+        dawg.build_infix_index();
+        let with = infixes.iter().map(|infix| dawg.containing(infix)).collect::<Vec<_>>();
+
+        assert_eq!(without, with);
+    }
+
+    #[test]
+    fn should_list_a_word_once_per_suffix() {
+        let dawg = setup_dawg();
+        let index = InfixIndex::new(&dawg);
+
+        // "ANA" appears twice in "BANANA" and is the end of "CABANA"
+        assert_eq!(index.ranks(&dawg.tokenize("ANA")), vec![dawg.rank("BANANA").unwrap(), dawg.rank("CABANA").unwrap()]);
+    }
+
+    #[test]
+    fn should_only_match_whole_tiles() {
+        let mut dawg = Dawg::with_tokenizer(Tiles::new(["LL"]));
+        let mut words = ["LLAMA", "CALLE", "PALA", "ALMA"].map(String::from);
+        dawg.sort_words(&mut words);
+        for word in words {
+            dawg.insert(word);
+        }
+        dawg.finish();
+
+        // "L" is not a letter of "LLAMA" or "CALLE", it is part of the "LL" tile
+        let infixes = ["L", "LL", "LA", "ALL", "A"];
+        let without = infixes.iter().map(|infix| dawg.containing(infix)).collect::<Vec<_>>();
+        dawg.build_infix_index();
+        let with = infixes.iter().map(|infix| dawg.containing(infix)).collect::<Vec<_>>();
+
+        assert_eq!(with, without);
+        assert_eq!(with[0], vec!["ALMA", "PALA"]);
+        assert_eq!(with[1], vec!["CALLE", "LLAMA"]);
+        assert_eq!(with[2], vec!["PALA"]);
+        assert_eq!(with[3], vec!["CALLE"]);
+        assert_eq!(with[4].len(), 4);
+    }
+}
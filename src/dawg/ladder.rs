@@ -0,0 +1,157 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+#[cfg(not(feature = "threading"))]
+use std::rc::Rc;
+#[cfg(feature = "threading")]
+use std::sync::Arc;
+
+use crate::dawg::dawg::Dawg;
+use crate::node::node::Node;
+
+#[cfg(test)]
+#[path = "./ladder.test.rs"]
+mod ladder_test;
+
+/// How far apart two words can be to be considered neighbours (see [`Dawg::neighbors`])
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Distance {
+    /// Words of the same length that differ by exactly one letter e.g. "COLD" -> "CORD"
+    Hamming,
+    /// Words one insertion, deletion or substitution apart e.g. "COLD" -> "COD", "SCOLD", "CORD"
+    Levenshtein,
+}
+
+/// The state of a neighbour search: the word being looked at and where we are in it
+struct Walk {
+    /// the letters of the word, split like the words of the dawg
+    letters: Vec<String>,
+    distance: Distance,
+    found: HashSet<String>,
+}
+
+impl Walk {
+    fn visit(&mut self, node: &Node, index: usize, word: &mut String, edited: bool) {
+        // the node is released before going any further, since a deletion visits it again: only its edges are kept
+        #[cfg(not(feature = "threading"))]
+        let (terminal, edges) = {
+            let current = node.borrow();
+            (current.terminal, current.edges.iter().map(|(letter, child)| (letter.to_owned(), Rc::clone(child))).collect::<Vec<_>>())
+        };
+        #[cfg(feature = "threading")]
+        let (terminal, edges) = {
+            let current = node.lock().unwrap();
+            (current.terminal, current.edges.iter().map(|(letter, child)| (letter.to_owned(), Arc::clone(child))).collect::<Vec<_>>())
+        };
+
+        let can_edit = !edited && self.distance == Distance::Levenshtein;
+
+        if index == self.letters.len() && edited && terminal {
+            self.found.insert(word.to_owned());
+        }
+
+        // delete the current letter of the word
+        if can_edit && index < self.letters.len() {
+            self.visit(node, index + 1, word, true);
+        }
+
+        for (letter, child) in &edges {
+            let length = word.len();
+            word.push_str(letter);
+
+            match self.letters.get(index) {
+                Some(expected) if expected == letter => self.visit(child, index + 1, word, edited),
+                Some(_) if !edited => self.visit(child, index + 1, word, true),
+                _ => {}
+            }
+
+            // insert a letter before the current letter of the word
+            if can_edit {
+                self.visit(child, index, word, true);
+            }
+
+            word.truncate(length);
+        }
+    }
+}
+
+impl Dawg {
+    /// Returns (in the order of the dawg) every word in the lexicon that is exactly one edit away from `word`,
+    /// where an edit is a substitution for [`Distance::Hamming`], or an insertion, deletion or substitution for [`Distance::Levenshtein`]
+    /// The neighbours are found by walking the dawg with a budget of one edit, rather than generating and checking every candidate
+    ///
+    /// ```rust
+    /// use dawg::{Dawg, Distance};
+    ///
+    /// let mut words = vec!["COD", "COLD", "CORD", "SCOLD", "WARM", "CARD"];
+    /// words.sort();
+    ///
+    /// let mut lexicon = Dawg::new();
+    /// for word in words {
+    ///     lexicon.insert(word.to_string());
+    /// }
+    /// lexicon.finish();
+    ///
+    /// assert_eq!(lexicon.neighbors("COLD", Distance::Hamming), vec!["CORD"]);
+    /// assert_eq!(lexicon.neighbors("COLD", Distance::Levenshtein), vec!["COD", "CORD", "SCOLD"]);
+    /// ```
+    pub fn neighbors(&self, word: impl AsRef<str>, distance: Distance) -> Vec<String> {
+        let mut walk = Walk {
+            letters: self.tokenize(word),
+            distance,
+            found: HashSet::new(),
+        };
+
+        walk.visit(&self.get_root(), 0, &mut String::new(), false);
+        let mut found = walk.found.into_iter().collect::<Vec<_>>();
+        self.sort_words(&mut found);
+        found
+    }
+
+    /// Returns the shortest chain of words from `from` to `to` (both included), where every word is a neighbour of the previous one (Word Ladder)
+    /// Returns None if either word is not in the lexicon or if there is no such chain
+    ///
+    /// ```rust
+    /// use dawg::{Dawg, Distance};
+    ///
+    /// let mut words = vec!["COLD", "CORD", "CARD", "WARD", "WARM", "WORM", "WORD"];
+    /// words.sort();
+    ///
+    /// let mut lexicon = Dawg::new();
+    /// for word in words {
+    ///     lexicon.insert(word.to_string());
+    /// }
+    /// lexicon.finish();
+    ///
+    /// let ladder = lexicon.ladder("COLD", "WARM", Distance::Hamming).unwrap();
+    /// assert_eq!(ladder, vec!["COLD", "CORD", "CARD", "WARD", "WARM"]);
+    /// ```
+    pub fn ladder(&self, from: impl AsRef<str>, to: impl AsRef<str>, distance: Distance) -> Option<Vec<String>> {
+        let from = self.is_word(from, true)?;
+        let to = self.is_word(to, true)?;
+
+        // maps every word reached to the word it was reached from
+        let mut previous: HashMap<String, Option<String>> = HashMap::from([(from.to_owned(), None)]);
+        let mut queue = VecDeque::from([from]);
+
+        while let Some(word) = queue.pop_front() {
+            if word == to {
+                let mut ladder = vec![word];
+
+                while let Some(Some(before)) = previous.get(ladder.last().unwrap()) {
+                    ladder.push(before.to_owned());
+                }
+
+                ladder.reverse();
+                return Some(ladder);
+            }
+
+            for neighbour in self.neighbors(&word, distance) {
+                if !previous.contains_key(&neighbour) {
+                    previous.insert(neighbour.to_owned(), Some(word.to_owned()));
+                    queue.push_back(neighbour);
+                }
+            }
+        }
+
+        None
+    }
+}
@@ -0,0 +1,99 @@
+#[cfg(test)]
+mod test_ladder {
+    use crate::dawg::collation::Alphabet;
+    use crate::dawg::dawg::Dawg;
+    use crate::dawg::ladder::Distance;
+    use crate::dawg::symbol::Tiles;
+
+    fn setup_dawg() -> Dawg {
+        let mut words = vec![
+            "BAT", "BATH", "BATS", "CAT", "CATS", "COAT", "COT", "CUT", "AT", "ACT", "TAB", "DOG",
+            "ÒYA", "ÒYÀ", "AYÀ",
+        ];
+        words.sort();
+
+        let mut dawg = Dawg::new();
+        for word in words {
+            dawg.insert(word.to_string());
+        }
+        dawg.finish();
+        dawg
+    }
+
+    #[test]
+    fn should_return_words_one_substitution_away() {
+        let dawg = setup_dawg();
+
+        assert_eq!(dawg.neighbors("CAT", Distance::Hamming), vec!["BAT", "COT", "CUT"]);
+        assert_eq!(dawg.neighbors("COT", Distance::Hamming), vec!["CAT", "CUT"]);
+        assert!(dawg.neighbors("DOG", Distance::Hamming).is_empty());
+        // the word itself does not have to be in the lexicon
+        assert_eq!(dawg.neighbors("CAX", Distance::Hamming), vec!["CAT"]);
+    }
+
+    #[test]
+    fn should_return_words_one_edit_away() {
+        let dawg = setup_dawg();
+
+        assert_eq!(
+            dawg.neighbors("CAT", Distance::Levenshtein),
+            vec!["AT", "BAT", "CATS", "COAT", "COT", "CUT"]
+        );
+        assert_eq!(dawg.neighbors("BATHS", Distance::Levenshtein), vec!["BATH", "BATS"]);
+    }
+
+    #[test]
+    fn should_treat_accented_letters_as_single_letters() {
+        let dawg = setup_dawg();
+
+        assert_eq!(dawg.neighbors("ÒYA", Distance::Hamming), vec!["ÒYÀ"]);
+        assert_eq!(dawg.neighbors("ÒYÀ", Distance::Hamming), vec!["AYÀ", "ÒYA"]);
+    }
+
+    #[test]
+    fn should_find_the_shortest_ladder() {
+        let dawg = setup_dawg();
+
+        assert_eq!(dawg.ladder("BAT", "CUT", Distance::Hamming).unwrap(), vec!["BAT", "CAT", "CUT"]);
+        assert_eq!(dawg.ladder("CAT", "CAT", Distance::Hamming).unwrap(), vec!["CAT"]);
+        assert_eq!(dawg.ladder("AT", "BATS", Distance::Levenshtein).unwrap(), vec!["AT", "BAT", "BATS"]);
+    }
+
+    #[test]
+    fn should_return_none_when_there_is_no_ladder() {
+        let dawg = setup_dawg();
+
+        assert!(dawg.ladder("CAT", "DOG", Distance::Hamming).is_none());
+        assert!(dawg.ladder("AT", "BATS", Distance::Hamming).is_none());
+        assert!(dawg.ladder("CAT", "COW", Distance::Hamming).is_none());
+    }
+
+    #[test]
+    fn should_edit_multi_letter_tiles_as_one_letter() {
+        let mut dawg = Dawg::with_tokenizer(Tiles::new(["LL"]));
+        let mut words = ["LLAMA", "LAMA", "CAMA", "AMA"].map(String::from);
+        dawg.sort_words(&mut words);
+        for word in words {
+            dawg.insert(word);
+        }
+        dawg.finish();
+
+        // replacing "LL" with "L" is a single substitution
+        assert_eq!(dawg.neighbors("LLAMA", Distance::Hamming), vec!["CAMA", "LAMA"]);
+        assert_eq!(dawg.neighbors("LLAMA", Distance::Levenshtein), vec!["AMA", "CAMA", "LAMA"]);
+        assert_eq!(dawg.ladder("CAMA", "LLAMA", Distance::Hamming).unwrap(), vec!["CAMA", "LLAMA"]);
+    }
+
+    #[test]
+    fn should_return_the_neighbours_in_the_order_of_the_collator() {
+        let mut dawg = Dawg::with_collator(Alphabet::spanish());
+        let mut words = ["MAZO", "MAÑO", "MANO"].map(String::from);
+        dawg.sort_words(&mut words);
+        for word in words {
+            dawg.insert(word);
+        }
+        dawg.finish();
+
+        assert_eq!(dawg.neighbors("MAXO", Distance::Hamming), vec!["MANO", "MAÑO", "MAZO"]);
+    }
+}
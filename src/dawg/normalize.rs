@@ -0,0 +1,152 @@
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+use crate::dawg::case::CaseFolding;
+use crate::dawg::dawg::Dawg;
+use crate::node::node::Node;
+
+#[cfg(test)]
+#[path = "./normalize.test.rs"]
+mod normalize_test;
+
+/// The unicode normalization form applied to words before they are split into letters,
+/// so that e.g. "Ò" typed as one code point or as "O" followed by a combining grave accent are the same letter
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Normalization {
+    /// Canonical composition (the form most keyboards produce)
+    Nfc,
+    /// Canonical decomposition
+    Nfd,
+    /// Compatibility composition, also folds e.g. ligatures ("ﬁ" -> "fi") and full width letters
+    Nfkc,
+    /// Compatibility decomposition
+    Nfkd,
+}
+
+impl Normalization {
+    pub fn apply(&self, text: &str) -> String {
+        match self {
+            Self::Nfc => text.nfc().collect(),
+            Self::Nfd => text.nfd().collect(),
+            Self::Nfkc => text.nfkc().collect(),
+            Self::Nfkd => text.nfkd().collect(),
+        }
+    }
+}
+
+/// Letters whose diacritic is a stroke through them: they have no decomposition, so the stroke is not a combining mark that can be dropped
+const STROKED: [(char, char); 12] = [
+    ('đ', 'd'), ('Đ', 'D'), ('ø', 'o'), ('Ø', 'O'), ('ł', 'l'), ('Ł', 'L'),
+    ('ħ', 'h'), ('Ħ', 'H'), ('ŧ', 't'), ('Ŧ', 'T'), ('ƀ', 'b'), ('Ƀ', 'B'),
+];
+
+/// Removes the diacritics (tone marks, accents, strokes...) from `text` e.g. "ÒYÀ" -> "OYA", "Đà" -> "Da"
+pub(crate) fn strip_marks(text: &str) -> String {
+    text.nfd()
+        .filter(|letter| !is_combining_mark(*letter))
+        .map(|letter| STROKED.iter().find(|(stroked, _)| *stroked == letter).map_or(letter, |(_, plain)| *plain))
+        .collect()
+}
+
+/// A diacritic-insensitive search, with the stored (accented) words found so far
+struct FoldedSearch {
+    /// the word searched for without its diacritics (and case folded, when the search is case insensitive)
+    target: String,
+    /// how letters are case folded, None when the search is case sensitive
+    case_folding: Option<CaseFolding>,
+    found: HashSet<String>,
+}
+
+impl FoldedSearch {
+    /// Removes the diacritics of a letter, after folding its case so that e.g. the dot of the Turkish "İ" is folded with it
+    fn fold(&self, letter: &str) -> String {
+        match self.case_folding {
+            Some(case_folding) => strip_marks(&case_folding.fold(letter)),
+            None => strip_marks(letter),
+        }
+    }
+
+    /// `read` is the length of `target` matched so far, a letter may fold to more than one (e.g. "ß" -> "ss")
+    fn visit(&mut self, node: &Node, word: &mut String, read: usize) {
+        #[cfg(not(feature = "threading"))]
+        let current = node.borrow();
+        #[cfg(feature = "threading")]
+        let current = node.lock().unwrap();
+
+        if read == self.target.len() && current.terminal {
+            self.found.insert(word.to_owned());
+        }
+
+        for (letter, child) in current.sorted_edges() {
+            // a letter that is only a mark (e.g. a lone combining accent) folds to nothing, and is skipped over
+            let folded = self.fold(&letter);
+            if !self.target[read..].starts_with(&folded) {
+                continue;
+            }
+
+            let length = word.len();
+            word.push_str(&letter);
+            self.visit(&child, word, read + folded.len());
+            word.truncate(length);
+        }
+    }
+}
+
+impl Dawg {
+    /// Creates a dawg that normalizes words (see [`Normalization`]) before splitting them into letters,
+    /// both when they are inserted and when they are looked up.
+    /// Words are expected to be sorted in their normalized form
+    ///
+    /// ```rust
+    /// use dawg::{Dawg, Normalization};
+    ///
+    /// let mut lexicon = Dawg::with_normalization(Normalization::Nfc);
+    /// lexicon.insert("O\u{300}YA\u{300}".to_string()); // "ÒYÀ" with combining accents
+    /// lexicon.finish();
+    ///
+    /// assert!(lexicon.is_word("\u{D2}Y\u{C0}", true).is_some()); // "ÒYÀ" with precomposed letters
+    /// ```
+    pub fn with_normalization(normalization: Normalization) -> Self {
+        let mut dawg = Self::default();
+        dawg.set_normalization(normalization);
+        dawg
+    }
+
+    /// Sets the normalization applied to words (see [`Dawg::with_normalization`]), this should be done before inserting any word
+    pub fn set_normalization(&mut self, normalization: Normalization) {
+        self.normalization = Some(normalization);
+    }
+
+    /// Returns (in the order of the dawg) the words of the lexicon that match `word` once their diacritics are ignored,
+    /// in their stored (accented) form e.g. "OYA" finds "ÒYÀ" and "ÓYÁ". A case insensitive search folds letters with the
+    /// [`CaseFolding`] of the dawg
+    ///
+    /// ```rust
+    /// use dawg::{Dawg, Normalization};
+    ///
+    /// let mut lexicon = Dawg::with_normalization(Normalization::Nfc);
+    /// for word in ["AYÒ", "ÒYA", "ÒYÀ"] {
+    ///     lexicon.insert(word.to_string());
+    /// }
+    /// lexicon.finish();
+    ///
+    /// assert_eq!(lexicon.find_ignoring_marks("oya", false), vec!["ÒYA", "ÒYÀ"]);
+    /// assert_eq!(lexicon.find_ignoring_marks("AYO", true), vec!["AYÒ"]);
+    /// ```
+    pub fn find_ignoring_marks(&self, word: impl AsRef<str>, case_sensitive: bool) -> Vec<String> {
+        let mut search = FoldedSearch {
+            target: String::new(),
+            case_folding: (!case_sensitive).then_some(self.case_folding),
+            found: HashSet::new(),
+        };
+        search.target = search.fold(&self.normalize(word));
+
+        search.visit(&self.get_root(), &mut String::new(), 0);
+        let mut found = search.found.into_iter().collect::<Vec<_>>();
+        self.sort_words(&mut found);
+        found
+    }
+}
@@ -0,0 +1,123 @@
+#[cfg(test)]
+mod test_normalize {
+    use crate::dawg::case::CaseFolding;
+    use crate::dawg::dawg::Dawg;
+    use crate::dawg::normalize::{strip_marks, Normalization};
+
+    // "AYÒ" and "ÒYÀ" with precomposed letters, and with combining marks
+    const COMPOSED: [&str; 2] = ["AY\u{D2}", "\u{D2}Y\u{C0}"];
+    const DECOMPOSED: [&str; 2] = ["AYO\u{300}", "O\u{300}YA\u{300}"];
+
+    fn setup_dawg(normalization: Normalization, words: [&str; 2]) -> Dawg {
+        let mut words = words.map(|word| normalization.apply(word));
+        words.sort();
+
+        let mut dawg = Dawg::with_normalization(normalization);
+        for word in words {
+            dawg.insert(word);
+        }
+        dawg.finish();
+        dawg
+    }
+
+    #[test]
+    fn should_find_words_whatever_form_they_were_written_in() {
+        for normalization in [Normalization::Nfc, Normalization::Nfd, Normalization::Nfkc, Normalization::Nfkd] {
+            for inserted in [COMPOSED, DECOMPOSED] {
+                let dawg = setup_dawg(normalization, inserted);
+
+                for word in COMPOSED.iter().chain(DECOMPOSED.iter()) {
+                    assert!(dawg.is_word(word, true).is_some());
+                    assert!(dawg.lookup(word, false).is_some());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn should_not_match_different_forms_without_normalization() {
+        let mut dawg = Dawg::new();
+        dawg.insert(COMPOSED[0].to_string());
+        dawg.finish();
+
+        assert!(dawg.is_word(COMPOSED[0], true).is_some());
+        assert!(dawg.is_word(DECOMPOSED[0], true).is_none());
+    }
+
+    #[test]
+    fn should_fold_compatibility_characters() {
+        let mut dawg = Dawg::with_normalization(Normalization::Nfkc);
+        dawg.insert("FIND".to_string());
+        dawg.finish();
+
+        // "ﬁ" ligature and full width "Ｄ"
+        assert!(dawg.is_word("\u{FB01}nd", false).is_some());
+        assert!(dawg.is_word("FIN\u{FF24}", true).is_some());
+    }
+
+    #[test]
+    fn should_strip_diacritics() {
+        assert_eq!(strip_marks("\u{D2}Y\u{C0}"), "OYA");
+        assert_eq!(strip_marks("O\u{300}YA\u{300}"), "OYA");
+        assert_eq!(strip_marks("Việt"), "Viet");
+        // the stroke of these letters is not a combining mark
+        assert_eq!(strip_marks("Đường"), "Duong");
+        assert_eq!(strip_marks("Łódź Ø"), "Lodz O");
+    }
+
+    #[test]
+    fn should_fold_the_case_of_unaccented_queries_like_the_dawg() {
+        let mut words = ["STRAßE", "İSTANBUL", "ışık"].map(String::from);
+        words.sort();
+
+        let mut dawg = Dawg::with_normalization(Normalization::Nfc);
+        dawg.set_case_folding(CaseFolding::Turkic);
+        for word in words {
+            dawg.insert(word);
+        }
+        dawg.finish();
+
+        assert_eq!(dawg.find_ignoring_marks("strasse", false), vec!["STRAßE"]);
+        assert_eq!(dawg.find_ignoring_marks("istanbul", false), vec!["İSTANBUL"]);
+        assert_eq!(dawg.find_ignoring_marks("IŞIK", false), vec!["ışık"]);
+        assert!(dawg.find_ignoring_marks("ISTANBUL", false).is_empty());
+        assert!(dawg.find_ignoring_marks("strasse", true).is_empty());
+    }
+
+    #[test]
+    fn should_return_the_accented_forms_of_unaccented_queries() {
+        for normalization in [Normalization::Nfc, Normalization::Nfd] {
+            let dawg = setup_dawg(normalization, COMPOSED);
+
+            assert_eq!(dawg.find_ignoring_marks("OYA", true), vec![normalization.apply(COMPOSED[1])]);
+            assert_eq!(dawg.find_ignoring_marks("ayo", false), vec![normalization.apply(COMPOSED[0])]);
+            assert_eq!(dawg.find_ignoring_marks("\u{D3}YA", true), vec![normalization.apply(COMPOSED[1])]);
+            assert!(dawg.find_ignoring_marks("ayo", true).is_empty());
+            assert!(dawg.find_ignoring_marks("OY", true).is_empty());
+        }
+
+        let mut words = ["VIỆT", "VIET", "VIÊN"].map(String::from);
+        words.sort();
+
+        let mut dawg = Dawg::new();
+        for word in words {
+            dawg.insert(word);
+        }
+        dawg.finish();
+
+        assert_eq!(dawg.find_ignoring_marks("viet", false), vec!["VIET", "VIỆT"]);
+
+        let mut words = ["ĐƯỜNG", "ĐẸP", "DỄ"].map(String::from);
+        words.sort();
+
+        let mut dawg = Dawg::with_normalization(Normalization::Nfc);
+        for word in words {
+            dawg.insert(word);
+        }
+        dawg.finish();
+
+        assert_eq!(dawg.find_ignoring_marks("DUONG", true), vec!["ĐƯỜNG"]);
+        assert_eq!(dawg.find_ignoring_marks("DEP", true), vec!["ĐẸP"]);
+        assert_eq!(dawg.find_ignoring_marks("DE", true), vec!["DỄ"]);
+    }
+}
@@ -0,0 +1,106 @@
+use crate::dawg::dawg::Dawg;
+use crate::node::node::Node;
+
+#[cfg(test)]
+#[path = "./prefix.test.rs"]
+mod prefix_test;
+
+/// A word of the lexicon found at the start of a text, see [`Dawg::prefixes_of`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrefixMatch {
+    /// the word as it is stored in the dawg (normalized, if the dawg normalizes words)
+    pub word: String,
+    /// the length of the word in bytes in the text as it was given, i.e. where the rest of the text starts
+    pub bytes: usize,
+    /// the length of the word in tokens, the letters the dawg splits words into: a tile counts as one token (see [`Tiles`](crate::Tiles)),
+    /// and so does a grapheme with combining marks unless a tokenizer splits it further
+    pub tokens: usize,
+}
+
+/// A lazy iterator over the words that the start of a text is made of, from the shortest to the longest
+/// Returned by [`Dawg::prefixes_of`]
+pub struct Prefixes<'a> {
+    dawg: &'a Dawg,
+    text: &'a str,
+    /// the node reached by the letters read so far, None once no word continues with them
+    node: Option<Node>,
+    word: String,
+    /// the bytes of the text and the tokens read so far
+    bytes: usize,
+    tokens: usize,
+}
+
+impl Iterator for Prefixes<'_> {
+    type Item = PrefixMatch;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(mut node) = self.node.take() {
+            // the text is read one piece at a time (a letter, or a tile), a word can only end between two pieces
+            let (letters, length) = self.dawg.first_letters(&self.text[self.bytes..])?;
+
+            for letter in &letters {
+                #[cfg(not(feature = "threading"))]
+                let next = node.borrow().edges.get(letter).cloned();
+                #[cfg(feature = "threading")]
+                let next = node.lock().unwrap().edges.get(letter).cloned();
+
+                node = next?;
+                self.word.push_str(letter);
+            }
+
+            self.bytes += length;
+            self.tokens += letters.len();
+
+            #[cfg(not(feature = "threading"))]
+            let terminal = node.borrow().terminal;
+            #[cfg(feature = "threading")]
+            let terminal = node.lock().unwrap().terminal;
+
+            self.node = Some(node);
+
+            if terminal && !letters.is_empty() {
+                return Some(PrefixMatch { word: self.word.to_owned(), bytes: self.bytes, tokens: self.tokens });
+            }
+        }
+
+        None
+    }
+}
+
+impl Dawg {
+    /// Returns the words of the lexicon that `text` starts with, from the shortest to the longest (the search is case sensitive)
+    /// The dawg is walked once, down the letters of `text`, until no word continues with them: the text is split as it is read,
+    /// so only the letters up to the longest word (and one more) are ever looked at
+    ///
+    /// ```rust
+    /// use dawg::Dawg;
+    ///
+    /// let mut lexicon = Dawg::new();
+    /// for word in ["NEW", "NEW YORK", "NEW YORK CITY", "YORK"] {
+    ///     lexicon.insert(word.to_string());
+    /// }
+    /// lexicon.finish();
+    ///
+    /// let text = "NEW YORK CITIES";
+    /// let words = lexicon.prefixes_of(text).map(|found| found.word).collect::<Vec<_>>();
+    /// assert_eq!(words, vec!["NEW", "NEW YORK"]);
+    ///
+    /// let longest = lexicon.longest_prefix_of(text).unwrap();
+    /// assert_eq!(&text[longest.bytes..], " CITIES");
+    /// ```
+    pub fn prefixes_of<'a>(&'a self, text: &'a str) -> Prefixes<'a> {
+        Prefixes {
+            dawg: self,
+            text,
+            node: Some(self.get_root()),
+            word: String::new(),
+            bytes: 0,
+            tokens: 0,
+        }
+    }
+
+    /// Returns the longest word of the lexicon that `text` starts with (see [`Dawg::prefixes_of`])
+    pub fn longest_prefix_of(&self, text: &str) -> Option<PrefixMatch> {
+        self.prefixes_of(text).last()
+    }
+}
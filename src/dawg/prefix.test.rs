@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod test_prefix {
+    use crate::dawg::dawg::Dawg;
+    use crate::dawg::normalize::Normalization;
+    use crate::dawg::prefix::PrefixMatch;
+    use crate::dawg::symbol::Tiles;
+
+    fn setup_dawg() -> Dawg {
+        let mut words = vec!["A", "AN", "ANT", "ANTENNA", "NEW", "NEW YORK", "ÒYÀ", "ÒY"];
+        words.sort();
+
+        let mut dawg = Dawg::new();
+        for word in words {
+            dawg.insert(word.to_string());
+        }
+        dawg.finish();
+        dawg
+    }
+
+    fn found(word: &str, tokens: usize) -> PrefixMatch {
+        PrefixMatch { word: word.to_string(), bytes: word.len(), tokens }
+    }
+
+    #[test]
+    fn should_return_every_word_the_text_starts_with() {
+        let dawg = setup_dawg();
+
+        assert_eq!(dawg.prefixes_of("ANTENNAS").collect::<Vec<_>>(), vec![found("A", 1), found("AN", 2), found("ANT", 3), found("ANTENNA", 7)]);
+        assert_eq!(dawg.prefixes_of("ANTE").collect::<Vec<_>>(), vec![found("A", 1), found("AN", 2), found("ANT", 3)]);
+        assert_eq!(dawg.prefixes_of("ÒYÀ DÉ").collect::<Vec<_>>(), vec![found("ÒY", 2), found("ÒYÀ", 3)]);
+        assert_eq!(dawg.prefixes_of("BANT").count(), 0);
+        assert_eq!(dawg.prefixes_of("").count(), 0);
+    }
+
+    #[test]
+    fn should_return_the_longest_word_the_text_starts_with() {
+        let dawg = setup_dawg();
+
+        assert_eq!(dawg.longest_prefix_of("NEW YORK TIMES"), Some(found("NEW YORK", 8)));
+        assert_eq!(dawg.longest_prefix_of("NEWS"), Some(found("NEW", 3)));
+        assert_eq!(dawg.longest_prefix_of("ÒYÀ"), Some(found("ÒYÀ", 3)));
+        assert_eq!(dawg.longest_prefix_of("NE"), None);
+    }
+
+    #[test]
+    fn should_split_a_text_into_the_longest_words() {
+        let dawg = setup_dawg();
+        let text = "NEW YORKANTENNAAN";
+        let mut rest = text;
+        let mut words = vec![];
+
+        while let Some(found) = dawg.longest_prefix_of(rest) {
+            words.push(found.word);
+            rest = &rest[found.bytes..];
+        }
+
+        assert_eq!(words, vec!["NEW YORK", "ANTENNA", "AN"]);
+        assert!(rest.is_empty());
+    }
+
+    #[test]
+    fn should_measure_the_words_in_the_text_as_given() {
+        let mut dawg = Dawg::with_normalization(Normalization::Nfd);
+        dawg.insert("\u{C9}".to_string());
+        dawg.finish();
+
+        // "É" is stored as "E" and a combining accent (3 bytes), but is 2 bytes in the text
+        let found = dawg.longest_prefix_of("\u{C9}X").unwrap();
+        assert_eq!(found, PrefixMatch { word: "E\u{301}".to_string(), bytes: 2, tokens: 1 });
+        assert_eq!(&"\u{C9}X"[found.bytes..], "X");
+
+        // the ligature is two letters once normalized, no word can end between them
+        let mut dawg = Dawg::with_normalization(Normalization::Nfkc);
+        for word in ["f", "fi", "fin"] {
+            dawg.insert(word.to_string());
+        }
+        dawg.finish();
+
+        let text = "\u{FB01}nfin";
+        let mut rest = text;
+        let mut words = vec![];
+        while let Some(found) = dawg.longest_prefix_of(rest) {
+            words.push(found.word);
+            rest = &rest[found.bytes..];
+        }
+
+        assert_eq!(words, vec!["fin", "fin"]);
+        assert!(rest.is_empty());
+        assert_eq!(dawg.prefixes_of(text).map(|found| found.bytes).collect::<Vec<_>>(), vec![3, 4]);
+    }
+
+    #[test]
+    fn should_read_the_text_one_tile_at_a_time() {
+        let mut dawg = Dawg::with_tokenizer(Tiles::new(["LL"]));
+        let mut words = ["L", "LA", "LLAMA"].map(String::from);
+        dawg.sort_words(&mut words);
+        for word in words {
+            dawg.insert(word);
+        }
+        dawg.finish();
+
+        // "LL" is a single tile, the text does not start with the word "L"
+        assert_eq!(dawg.prefixes_of("LLAMAS").collect::<Vec<_>>(), vec![found("LLAMA", 4)]);
+        assert_eq!(dawg.prefixes_of("LAMA").collect::<Vec<_>>(), vec![found("L", 1), found("LA", 2)]);
+    }
+}
@@ -0,0 +1,119 @@
+use crate::dawg::dawg::Dawg;
+
+#[cfg(test)]
+#[path = "./rank.test.rs"]
+mod rank_test;
+
+impl Dawg {
+    /// Returns the number of words smaller than `prefix` (alphabetically) and the number of words starting with `prefix`,
+    /// i.e. the range of ranks taken by the words starting with `prefix`. Returns None if no word starts with `prefix`
+    pub(crate) fn prefix_range(&self, prefix: impl AsRef<str>) -> Option<(usize, usize)> {
+        self.prefix_range_sequence(&self.tokenize(prefix))
+    }
+
+    /// Returns the range of ranks taken by the words starting with the letters `prefix`, see [`Dawg::prefix_range`]
+    pub(crate) fn prefix_range_sequence(&self, prefix: &[String]) -> Option<(usize, usize)> {
+        let mut node = self.get_root();
+        let mut smaller = 0;
+
+        for letter in prefix {
+            #[cfg(not(feature = "threading"))]
+            let current = node.borrow();
+            #[cfg(feature = "threading")]
+            let current = node.lock().unwrap();
+
+            // the word formed so far comes before all the words that extend it
+            if current.terminal {
+                smaller += 1;
+            }
+
+            let mut next = None;
+            for (key, child) in self.sorted_edges(&current) {
+                if key == *letter {
+                    next = Some(child);
+                    break;
+                }
+
+                #[cfg(not(feature = "threading"))]
+                { smaller += child.borrow().count; }
+                #[cfg(feature = "threading")]
+                { smaller += child.lock().unwrap().count; }
+            }
+
+            drop(current);
+            node = next?;
+        }
+
+        #[cfg(not(feature = "threading"))]
+        let count = node.borrow().count;
+        #[cfg(feature = "threading")]
+        let count = node.lock().unwrap().count;
+
+        if count == 0 {
+            return None;
+        }
+
+        Some((smaller, count))
+    }
+
+    /// Returns the position of `word` in the (alphabetically sorted) lexicon, or None if it is not a word in the lexicon
+    /// Together with [`Dawg::word_at`] this maps every word to a unique number in `0..number_of_words` (minimal perfect hashing),
+    /// which can be used to attach data to words in a separate `Vec`
+    ///
+    /// ```rust
+    /// use dawg::Dawg;
+    ///
+    /// let mut lexicon = Dawg::new();
+    /// for word in ["CAR", "CARE", "CAT", "DOG"] {
+    ///     lexicon.insert(word.to_string());
+    /// }
+    /// lexicon.finish();
+    ///
+    /// assert_eq!(lexicon.rank("CAT"), Some(2));
+    /// assert_eq!(lexicon.word_at(2), Some("CAT".to_string()));
+    /// assert_eq!(lexicon.rank("CA"), None);
+    /// ```
+    pub fn rank(&self, word: impl AsRef<str>) -> Option<usize> {
+        self.is_word(&word, true)?;
+        self.prefix_range(word).map(|(rank, _)| rank)
+    }
+
+    /// Returns the word at position `rank` in the (alphabetically sorted) lexicon, see [`Dawg::rank`]
+    pub fn word_at(&self, rank: usize) -> Option<String> {
+        let mut node = self.get_root();
+        let mut word = String::new();
+        let mut rank = rank;
+
+        loop {
+            #[cfg(not(feature = "threading"))]
+            let current = node.borrow();
+            #[cfg(feature = "threading")]
+            let current = node.lock().unwrap();
+
+            if current.terminal {
+                if rank == 0 {
+                    return Some(word);
+                }
+                rank -= 1;
+            }
+
+            let mut next = None;
+            for (key, child) in self.sorted_edges(&current) {
+                #[cfg(not(feature = "threading"))]
+                let count = child.borrow().count;
+                #[cfg(feature = "threading")]
+                let count = child.lock().unwrap().count;
+
+                if rank < count {
+                    word.push_str(&key);
+                    next = Some(child);
+                    break;
+                }
+                rank -= count;
+            }
+
+            drop(current);
+            node = next?;
+        }
+    }
+}
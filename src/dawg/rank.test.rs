@@ -0,0 +1,48 @@
+#[cfg(test)]
+mod test_rank {
+    use crate::dawg::dawg::Dawg;
+
+    fn setup_dawg() -> (Dawg, Vec<String>) {
+        let mut words = vec!["BAM", "BAT", "BATH", "BATHE", "CAR", "CARS", "CATH", "a", "alpa", "ÒYA", "AYÒ", "TEN", "TENT"]
+            .into_iter().map(|x| x.to_string()).collect::<Vec<_>>();
+        words.sort();
+
+        let mut dawg = Dawg::new();
+        for word in &words {
+            dawg.insert(word.to_owned());
+        }
+        dawg.finish();
+        (dawg, words)
+    }
+
+    #[test]
+    fn should_rank_every_word_by_its_alphabetical_position() {
+        let (dawg, words) = setup_dawg();
+
+        for (index, word) in words.iter().enumerate() {
+            assert_eq!(dawg.rank(word), Some(index));
+            assert_eq!(dawg.word_at(index).as_ref(), Some(word));
+        }
+
+        assert_eq!(dawg.word_at(words.len()), None);
+    }
+
+    #[test]
+    fn should_not_rank_words_outside_the_lexicon() {
+        let (dawg, _) = setup_dawg();
+
+        assert_eq!(dawg.rank("BA"), None);
+        assert_eq!(dawg.rank("BATHES"), None);
+        assert_eq!(dawg.rank("DOG"), None);
+    }
+
+    #[test]
+    fn should_return_the_ranks_of_the_words_with_a_prefix() {
+        let (dawg, _) = setup_dawg();
+
+        assert_eq!(dawg.prefix_range("BAT"), Some((2, 3)));
+        assert_eq!(dawg.prefix_range("TEN"), Some((8, 2)));
+        assert_eq!(dawg.prefix_range(""), Some((0, 13)));
+        assert_eq!(dawg.prefix_range("X"), None);
+    }
+}
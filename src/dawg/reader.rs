@@ -0,0 +1,141 @@
+use std::fmt::Display;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::Path;
+
+use crate::dawg::dawg::Dawg;
+
+#[cfg(test)]
+#[path = "./reader.test.rs"]
+mod reader_test;
+
+/// Why a word list could not be read into a dawg, see [`Dawg::from_reader`]
+#[derive(Debug)]
+pub enum ReadError {
+    /// The list could not be read (or is not valid UTF-8)
+    Io(io::Error),
+    /// A word comes before the previous one in the order of the dawg (`line` counts from 1)
+    Unsorted { line: usize, word: String, previous: String },
+}
+
+impl Display for ReadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "could not read the word list: {error}"),
+            Self::Unsorted { line, word, previous } => write!(f, "line {line}: \"{word}\" should come before \"{previous}\", the words must be sorted"),
+        }
+    }
+}
+
+impl std::error::Error for ReadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            Self::Unsorted { .. } => None,
+        }
+    }
+}
+
+impl From<io::Error> for ReadError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+/// How far the reading of a word list has gone, see [`Dawg::read_words`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Progress {
+    /// the words inserted so far
+    pub words: usize,
+    /// the nodes created so far (some of them are merged away as the dawg is minimized)
+    pub nodes: usize,
+    /// the nodes in the register of minimized nodes
+    pub register: usize,
+}
+
+impl Dawg {
+    /// Inserts the words of a list, one per line, as they are read: lines are trimmed, blank lines and comments (starting with "#") are skipped.
+    /// `progress` is called every `every` words (never if `every` is 0), then once all the words are read. The dawg is not finished, and the list is never held in memory.
+    /// Returns the number of words inserted, or an error at the first word out of order (the words before it are kept)
+    pub fn read_words(&mut self, mut reader: impl BufRead, every: usize, mut progress: impl FnMut(Progress)) -> Result<usize, ReadError> {
+        let mut line = String::new();
+        let mut number = 0;
+        let mut words = 0;
+
+        loop {
+            line.clear();
+            if reader.read_line(&mut line)? == 0 {
+                break;
+            }
+            number += 1;
+
+            let word = line.trim();
+            if word.is_empty() || word.starts_with('#') {
+                continue;
+            }
+
+            let letters = self.tokenize(word);
+            if self.compare_sequences(&self.previous_word, &letters).is_gt() {
+                return Err(ReadError::Unsorted { line: number, word: word.to_string(), previous: self.previous_word.concat() });
+            }
+
+            self.insert_sequence(letters);
+            words += 1;
+
+            if every != 0 && words % every == 0 {
+                progress(Progress { words, nodes: self.created_nodes(), register: self.register_size() });
+            }
+        }
+
+        progress(Progress { words, nodes: self.created_nodes(), register: self.register_size() });
+        Ok(words)
+    }
+
+    /// Builds a (finished) dawg from a sorted word list, one word per line (see [`Dawg::read_words`])
+    ///
+    /// ```rust
+    /// use dawg::Dawg;
+    ///
+    /// let list = "# animals\nBAT\nCAT\n\n  DOG  \n";
+    /// let lexicon = Dawg::from_reader(list.as_bytes()).unwrap();
+    ///
+    /// assert_eq!(lexicon.words().collect::<Vec<_>>(), vec!["BAT", "CAT", "DOG"]);
+    /// assert!(Dawg::from_reader("DOG\nCAT\n".as_bytes()).is_err());
+    /// ```
+    pub fn from_reader(reader: impl BufRead) -> Result<Self, ReadError> {
+        let mut dawg = Self::new();
+        dawg.read_words(reader, 0, |_| {})?;
+        dawg.finish();
+        Ok(dawg)
+    }
+
+    /// Builds a (finished) dawg from a sorted word list file, one word per line (see [`Dawg::read_words`])
+    /// A file ending in ".gz" is decompressed as it is read, which needs the `gzip` feature
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, ReadError> {
+        Self::from_path_with_progress(path, 0, |_| {})
+    }
+
+    /// Builds a (finished) dawg from a sorted word list file like [`Dawg::from_path`], calling `progress` every `every` words
+    pub fn from_path_with_progress(path: impl AsRef<Path>, every: usize, progress: impl FnMut(Progress)) -> Result<Self, ReadError> {
+        let path = path.as_ref();
+        let file = File::open(path)?;
+
+        let reader: Box<dyn BufRead> = if path.extension().is_some_and(|extension| extension == "gz") {
+            #[cfg(feature = "gzip")]
+            {
+                Box::new(BufReader::new(flate2::read::MultiGzDecoder::new(file)))
+            }
+            #[cfg(not(feature = "gzip"))]
+            {
+                return Err(io::Error::new(io::ErrorKind::Unsupported, "reading gzipped word lists needs the \"gzip\" feature").into());
+            }
+        } else {
+            Box::new(BufReader::new(file))
+        };
+
+        let mut dawg = Self::new();
+        dawg.read_words(reader, every, progress)?;
+        dawg.finish();
+        Ok(dawg)
+    }
+}
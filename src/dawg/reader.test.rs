@@ -0,0 +1,99 @@
+#[cfg(test)]
+mod test_reader {
+    use std::path::PathBuf;
+
+    use crate::dawg::dawg::Dawg;
+    use crate::dawg::reader::{Progress, ReadError};
+
+    fn sample_words() -> Vec<String> {
+        let mut words = include_str!("../sample_words.txt").lines().map(|word| word.trim().to_string()).filter(|word| !word.is_empty()).collect::<Vec<_>>();
+        words.sort();
+        words.dedup();
+        words
+    }
+
+    /// A file in the temporary directory, named after the test that writes it
+    fn temporary(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("dawg-reader-{}-{name}", std::process::id()))
+    }
+
+    #[test]
+    fn should_build_a_dawg_from_a_reader() {
+        let list = "# a comment\n\nBAM\r\n  BAT\nBATH\n\n# another one\nÒYÀ\n";
+        let dawg = Dawg::from_reader(list.as_bytes()).unwrap();
+
+        assert_eq!(dawg.words().collect::<Vec<_>>(), vec!["BAM", "BAT", "BATH", "ÒYÀ"]);
+        assert_eq!(Dawg::from_reader("".as_bytes()).unwrap().words().count(), 0);
+    }
+
+    #[test]
+    fn should_report_words_out_of_order() {
+        let error = Dawg::from_reader("BAT\n# comment\nBATH\nBAM\n".as_bytes()).unwrap_err();
+
+        assert!(matches!(&error, ReadError::Unsorted { line: 4, word, previous } if word == "BAM" && previous == "BATH"));
+        assert_eq!(error.to_string(), "line 4: \"BAM\" should come before \"BATH\", the words must be sorted");
+
+        let error = Dawg::from_reader([0x42, 0xff, 0x0a].as_slice()).unwrap_err();
+        assert!(matches!(error, ReadError::Io(_)));
+    }
+
+    #[test]
+    fn should_report_progress() {
+        let words = sample_words();
+        let list = words.join("\n");
+        let mut reports = vec![];
+
+        let mut dawg = Dawg::new();
+        let read = dawg.read_words(list.as_bytes(), 25, |progress| reports.push(progress)).unwrap();
+        dawg.finish();
+
+        assert_eq!(read, words.len());
+        assert_eq!(reports.len(), words.len() / 25 + 1);
+        assert_eq!(reports.iter().map(|progress| progress.words).take(2).collect::<Vec<_>>(), vec![25, 50]);
+        assert!(reports.windows(2).all(|pair| pair[0].nodes <= pair[1].nodes));
+        assert!(matches!(reports.last(), Some(Progress { words, .. }) if *words == read));
+        assert_eq!(dawg.words().collect::<Vec<_>>(), words);
+    }
+
+    #[test]
+    fn should_build_a_dawg_from_a_file() {
+        let words = sample_words();
+        let path = temporary("words.txt");
+        std::fs::write(&path, words.join("\n")).unwrap();
+
+        let mut calls = 0;
+        let dawg = Dawg::from_path_with_progress(&path, 10, |_| calls += 1);
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(dawg.unwrap().words().collect::<Vec<_>>(), words);
+        assert_eq!(calls, words.len() / 10 + 1);
+        assert!(matches!(Dawg::from_path(temporary("missing.txt")), Err(ReadError::Io(_))));
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn should_build_a_dawg_from_a_gzipped_file() {
+        use std::io::Write;
+
+        let words = sample_words();
+        let path = temporary("words.txt.gz");
+        let mut encoder = flate2::write::GzEncoder::new(std::fs::File::create(&path).unwrap(), flate2::Compression::default());
+        encoder.write_all(words.join("\n").as_bytes()).unwrap();
+        encoder.finish().unwrap();
+
+        let dawg = Dawg::from_path(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(dawg.unwrap().words().collect::<Vec<_>>(), words);
+    }
+
+    #[cfg(not(feature = "gzip"))]
+    #[test]
+    fn should_refuse_gzipped_files_without_the_feature() {
+        let path = temporary("words.txt.gz");
+        std::fs::write(&path, "").unwrap();
+
+        let dawg = Dawg::from_path(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert!(matches!(dawg, Err(ReadError::Io(error)) if error.kind() == std::io::ErrorKind::Unsupported));
+    }
+}
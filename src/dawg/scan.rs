@@ -0,0 +1,315 @@
+use std::borrow::Cow;
+use std::cmp::Reverse;
+use std::collections::{HashMap, VecDeque};
+use std::mem::take;
+
+use serde::{Deserialize, Serialize};
+
+use crate::dawg::dawg::Dawg;
+
+#[cfg(test)]
+#[path = "./scan.test.rs"]
+mod scan_test;
+
+/// Which occurrences [`Dawg::scan`] returns when words of the lexicon overlap in the text
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchKind {
+    /// Every occurrence of every word e.g. "SHE", "HE" and "HERS" in "USHERS"
+    Overlapping,
+    /// From left to right, the longest word starting the earliest, then the text after it e.g. only "SHE" in "USHERS"
+    LeftmostLongest,
+}
+
+/// How [`Dawg::scan`] matches the words of the lexicon in a text
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScanOptions {
+    pub kind: MatchKind,
+    /// Whether a match must be a whole word of the text: neither preceded nor followed by a letter or a digit
+    pub whole_words: bool,
+}
+
+impl Default for ScanOptions {
+    /// Leftmost-longest matches, anywhere in the text
+    fn default() -> Self {
+        Self { kind: MatchKind::LeftmostLongest, whole_words: false }
+    }
+}
+
+/// An occurrence of a word of the lexicon in a text, see [`Dawg::scan`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScanMatch {
+    /// where the word starts in the text, in bytes
+    pub start: usize,
+    /// where the word ends in the text (exclusive), in bytes
+    pub end: usize,
+    pub word: String,
+}
+
+/// An Aho-Corasick automaton of a lexicon, which finds every word of the lexicon in a text in a single pass.
+/// The dawg shares the ends of words, so one of its nodes can be reached by different prefixes and cannot tell where to fall back to
+/// when the text stops matching: the automaton is built over the trie of the words instead, each state knowing the longest suffix of its prefix
+/// that is also a prefix of a word (its failure link), and the closest such suffix that is a word (its output link)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Scanner {
+    /// the edges of every state of the trie, the root is state 0
+    edges: Vec<HashMap<String, usize>>,
+    /// the number of letters read to reach each state
+    depths: Vec<usize>,
+    terminals: Vec<bool>,
+    failures: Vec<usize>,
+    outputs: Vec<Option<usize>>,
+}
+
+impl Scanner {
+    /// Builds the automaton of a closed (finished) dawg
+    pub fn new(dawg: &Dawg) -> Self {
+        let mut scanner = Self { edges: vec![HashMap::new()], depths: vec![0], terminals: vec![false], failures: vec![0], outputs: vec![None] };
+
+        for letters in dawg.sequences() {
+            let mut state = 0;
+            for letter in letters {
+                state = match scanner.edges[state].get(&letter) {
+                    Some(next) => *next,
+                    None => {
+                        let next = scanner.edges.len();
+                        scanner.edges.push(HashMap::new());
+                        scanner.depths.push(scanner.depths[state] + 1);
+                        scanner.terminals.push(false);
+                        scanner.failures.push(0);
+                        scanner.outputs.push(None);
+                        scanner.edges[state].insert(letter, next);
+                        next
+                    }
+                };
+            }
+            scanner.terminals[state] = true;
+        }
+
+        // the links of a state are found from the links of its parent, so states are linked breadth first
+        let mut queue = scanner.edges[0].values().copied().collect::<VecDeque<_>>();
+        while let Some(state) = queue.pop_front() {
+            let edges = scanner.edges[state].iter().map(|(letter, next)| (letter.to_owned(), *next)).collect::<Vec<_>>();
+
+            for (letter, next) in edges {
+                let mut failure = scanner.failures[state];
+                while failure != 0 && !scanner.edges[failure].contains_key(&letter) {
+                    failure = scanner.failures[failure];
+                }
+
+                let failure = match scanner.edges[failure].get(&letter) {
+                    Some(target) if *target != next => *target,
+                    _ => 0,
+                };
+                scanner.failures[next] = failure;
+                scanner.outputs[next] = if scanner.terminals[failure] { Some(failure) } else { scanner.outputs[failure] };
+                queue.push_back(next);
+            }
+        }
+
+        scanner
+    }
+}
+
+/// Whether a letter of the text is part of a word (the neighbour of a whole word cannot be)
+fn is_word_letter(letter: &str) -> bool {
+    letter.chars().next().is_some_and(char::is_alphanumeric)
+}
+
+/// A lazy iterator over the occurrences of the words of a lexicon in a text, returned by [`Dawg::scan`]
+/// The text is read one piece at a time, and an occurrence is yielded as soon as no later letter can change it
+pub struct ScanMatches<'a> {
+    dawg: &'a Dawg,
+    scanner: Cow<'a, Scanner>,
+    text: &'a str,
+    options: ScanOptions,
+    /// the state of the automaton after the letters read so far
+    state: usize,
+    /// the bytes of the text and the letters read so far
+    bytes: usize,
+    read: usize,
+    /// the last letters read, the first of them being letter number `first` of the text
+    letters: VecDeque<String>,
+    first: usize,
+    /// (letter, byte) where a piece of the text ends, the only places where an occurrence can start or end
+    boundaries: VecDeque<(usize, usize)>,
+    /// occurrences (as the letters they start at and end before) waiting for the letter after them to be read
+    waiting: Vec<(usize, usize)>,
+    /// leftmost-longest occurrences that a longer one, or one starting earlier, may still replace
+    candidates: Vec<(usize, usize, ScanMatch)>,
+    /// where the last leftmost-longest occurrence ends, the next one cannot start before
+    cursor: usize,
+    ready: VecDeque<ScanMatch>,
+    done: bool,
+}
+
+impl ScanMatches<'_> {
+    /// Reads the next piece of the text (a letter, or a tile), or the end of the text
+    fn advance(&mut self) {
+        let Some((letters, length)) = self.dawg.first_letters(&self.text[self.bytes..]) else {
+            // nothing follows the last occurrences
+            for (start, end) in take(&mut self.waiting) {
+                self.confirm(start, end, None);
+            }
+            self.release(usize::MAX);
+            self.done = true;
+            return;
+        };
+
+        self.bytes += length;
+        let ends_letters = !letters.is_empty();
+        for letter in letters {
+            self.read_letter(letter);
+        }
+        if ends_letters {
+            self.boundaries.push_back((self.read, self.bytes));
+        }
+
+        // an occurrence found later starts after the letters leading to the current state, or is one of those waiting
+        let limit = self.waiting.iter().map(|(start, _)| *start).fold(self.read - self.scanner.depths[self.state], usize::min);
+        self.release(limit);
+
+        // the letter before an occurrence is kept for whole words
+        while self.boundaries.front().is_some_and(|(letter, _)| *letter < limit) {
+            self.boundaries.pop_front();
+        }
+        while self.first + 1 < limit {
+            self.letters.pop_front();
+            self.first += 1;
+        }
+    }
+
+    fn read_letter(&mut self, letter: String) {
+        for (start, end) in take(&mut self.waiting) {
+            self.confirm(start, end, Some(&letter));
+        }
+
+        let scanner = &self.scanner;
+        let mut state = self.state;
+        while state != 0 && !scanner.edges[state].contains_key(&letter) {
+            state = scanner.failures[state];
+        }
+        state = scanner.edges[state].get(&letter).copied().unwrap_or(0);
+
+        self.state = state;
+        self.letters.push_back(letter);
+        self.read += 1;
+
+        // the longest word ending here first
+        let mut output = if scanner.terminals[state] { Some(state) } else { scanner.outputs[state] };
+        while let Some(word) = output {
+            self.waiting.push((self.read - scanner.depths[word], self.read));
+            output = scanner.outputs[word];
+        }
+    }
+
+    /// Keeps an occurrence once the letter after it (`next`, None at the end of the text) is known
+    fn confirm(&mut self, start: usize, end: usize, next: Option<&str>) {
+        if self.options.whole_words {
+            let before = start.checked_sub(1).map(|index| self.letters[index - self.first].as_str());
+            if before.is_some_and(is_word_letter) || next.is_some_and(is_word_letter) {
+                return;
+            }
+        }
+
+        // a word cannot start or end inside a piece of the text
+        let offset = |letter: usize| self.boundaries.binary_search_by_key(&letter, |(letter, _)| *letter).ok().map(|index| self.boundaries[index].1);
+        let (Some(start_byte), Some(end_byte)) = (offset(start), offset(end)) else { return };
+
+        let word = self.letters.range(start - self.first..end - self.first).map(String::as_str).collect::<String>();
+        let found = ScanMatch { start: start_byte, end: end_byte, word };
+
+        match self.options.kind {
+            MatchKind::Overlapping => self.ready.push_back(found),
+            MatchKind::LeftmostLongest => if start >= self.cursor {
+                self.candidates.push((start, end, found));
+            },
+        }
+    }
+
+    /// Yields the leftmost-longest occurrences starting before `limit`: no occurrence found later can start before it
+    fn release(&mut self, limit: usize) {
+        while let Some((start, end, found)) = self.candidates.iter().min_by_key(|(start, end, _)| (*start, Reverse(*end))).cloned() {
+            if start >= limit {
+                break;
+            }
+
+            self.ready.push_back(found);
+            self.cursor = end;
+            self.candidates.retain(|(start, _, _)| *start >= end);
+        }
+    }
+}
+
+impl Iterator for ScanMatches<'_> {
+    type Item = ScanMatch;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(found) = self.ready.pop_front() {
+                return Some(found);
+            }
+            if self.done {
+                return None;
+            }
+            self.advance();
+        }
+    }
+}
+
+impl Dawg {
+    /// Builds the Aho-Corasick automaton of the lexicon used by [`Dawg::scan`]
+    /// Call this once the dawg is closed (after [`Dawg::finish`]), the automaton takes more memory than the dawg itself (it does not share the ends of words)
+    pub fn build_scanner(&mut self) {
+        self.scanner = Some(Box::new(Scanner::new(self)));
+    }
+
+    /// Returns a lazy iterator over the occurrences of the words of the lexicon in `text` (the search is case sensitive).
+    /// The text is read once, one piece at a time (a letter, or a tile), by the automaton built with [`Dawg::build_scanner`];
+    /// without it, an automaton is built for this scan alone, which takes time in proportion to the size of the lexicon.
+    ///
+    /// Offsets are in bytes of `text` as it was given (not as normalized, if the dawg normalizes words), so a word can only start and end
+    /// between two pieces of the text, like in [`Dawg::prefixes_of`]. Overlapping occurrences are yielded in the order they end
+    /// (the longest first when several end together), leftmost-longest ones from left to right
+    ///
+    /// ```rust
+    /// use dawg::{Dawg, MatchKind, ScanOptions};
+    ///
+    /// let mut words = vec!["HE", "HERS", "SHE", "HIS"];
+    /// words.sort();
+    ///
+    /// let mut lexicon = Dawg::new();
+    /// for word in words {
+    ///     lexicon.insert(word.to_string());
+    /// }
+    /// lexicon.finish();
+    /// lexicon.build_scanner();
+    ///
+    /// let words = |options| lexicon.scan("USHERS", &options).map(|found| found.word).collect::<Vec<_>>();
+    /// assert_eq!(words(ScanOptions { kind: MatchKind::Overlapping, whole_words: false }), vec!["SHE", "HE", "HERS"]);
+    /// assert_eq!(words(ScanOptions::default()), vec!["SHE"]);
+    /// ```
+    pub fn scan<'a>(&'a self, text: &'a str, options: &ScanOptions) -> ScanMatches<'a> {
+        let scanner = match &self.scanner {
+            Some(scanner) => Cow::Borrowed(scanner.as_ref()),
+            None => Cow::Owned(Scanner::new(self)),
+        };
+
+        ScanMatches {
+            dawg: self,
+            scanner,
+            text,
+            options: *options,
+            state: 0,
+            bytes: 0,
+            read: 0,
+            letters: VecDeque::new(),
+            first: 0,
+            boundaries: VecDeque::from([(0, 0)]),
+            waiting: vec![],
+            candidates: vec![],
+            cursor: 0,
+            ready: VecDeque::new(),
+            done: false,
+        }
+    }
+}
@@ -0,0 +1,171 @@
+#[cfg(test)]
+mod test_scan {
+    use std::cmp::Reverse;
+
+    use crate::dawg::dawg::Dawg;
+    use crate::dawg::normalize::Normalization;
+    use crate::dawg::scan::{MatchKind, ScanMatch, ScanOptions};
+    use crate::dawg::symbol::Tiles;
+
+    fn setup_dawg() -> Dawg {
+        let mut words = vec!["HE", "HERS", "HIS", "SHE", "CAT", "CATALOG", "LOG", "ÒYÀ"];
+        words.sort();
+
+        let mut dawg = Dawg::new();
+        for word in words {
+            dawg.insert(word.to_string());
+        }
+        dawg.finish();
+        dawg
+    }
+
+    fn scan(dawg: &Dawg, text: &str, options: &ScanOptions) -> Vec<ScanMatch> {
+        dawg.scan(text, options).collect()
+    }
+
+    fn found(start: usize, end: usize, word: &str) -> ScanMatch {
+        ScanMatch { start, end, word: word.to_string() }
+    }
+
+    const OVERLAPPING: ScanOptions = ScanOptions { kind: MatchKind::Overlapping, whole_words: false };
+
+    #[test]
+    fn should_find_every_occurrence() {
+        let mut dawg = setup_dawg();
+        dawg.build_scanner();
+
+        assert_eq!(scan(&dawg, "USHERS", &OVERLAPPING), vec![found(1, 4, "SHE"), found(2, 4, "HE"), found(2, 6, "HERS")]);
+        assert_eq!(scan(&dawg, "THE CATALOG", &OVERLAPPING), vec![
+            found(1, 3, "HE"),
+            found(4, 7, "CAT"),
+            found(4, 11, "CATALOG"),
+            found(8, 11, "LOG"),
+        ]);
+        // offsets are in bytes
+        assert_eq!(scan(&dawg, "ÀÒYÀHIS", &OVERLAPPING), vec![found(2, 7, "ÒYÀ"), found(7, 10, "HIS")]);
+        assert!(scan(&dawg, "", &OVERLAPPING).is_empty());
+        assert!(scan(&dawg, "NOTHING", &OVERLAPPING).is_empty());
+    }
+
+    #[test]
+    fn should_keep_the_leftmost_longest_occurrences() {
+        let mut dawg = setup_dawg();
+        dawg.build_scanner();
+        let options = ScanOptions::default();
+
+        assert_eq!(scan(&dawg, "USHERS", &options), vec![found(1, 4, "SHE")]);
+        assert_eq!(scan(&dawg, "THE CATALOG", &options), vec![found(1, 3, "HE"), found(4, 11, "CATALOG")]);
+        assert_eq!(scan(&dawg, "HISHERS", &options), vec![found(0, 3, "HIS"), found(3, 7, "HERS")]);
+    }
+
+    #[test]
+    fn should_only_match_whole_words() {
+        let mut dawg = setup_dawg();
+        dawg.build_scanner();
+
+        let options = ScanOptions { kind: MatchKind::Overlapping, whole_words: true };
+        assert_eq!(scan(&dawg, "HE SAID HIS CATALOG, THE CAT'S", &options), vec![
+            found(0, 2, "HE"),
+            found(8, 11, "HIS"),
+            found(12, 19, "CATALOG"),
+            found(25, 28, "CAT"),
+        ]);
+
+        // the longest occurrence is not a whole word, the shorter one is
+        let mut dawg = Dawg::new();
+        dawg.insert("NEW".to_string());
+        dawg.insert("NEWS".to_string());
+        dawg.finish();
+        let options = ScanOptions { kind: MatchKind::LeftmostLongest, whole_words: true };
+        assert_eq!(scan(&dawg, "NEWSY NEW", &options), vec![found(6, 9, "NEW")]);
+    }
+
+    #[test]
+    fn should_find_the_occurrences_a_walk_from_every_letter_finds() {
+        let mut dawg = setup_dawg();
+        let texts = ["USHERS", "THE CATALOG OF HIS HERSHE", "SHESHESHE", "CATALOGCATHISHE", ""];
+
+        // the words starting at every letter, found by walking the dawg
+        let walk = |dawg: &Dawg, text: &str| (0..text.len())
+            .flat_map(|start| dawg.prefixes_of(&text[start..]).map(move |found| (start, start + found.bytes)).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        for built in [false, true] {
+            if built {
+                dawg.build_scanner();
+            }
+
+            for text in texts {
+                let mut overlapping = walk(&dawg, text);
+                overlapping.sort_by_key(|(start, end)| (*end, *start));
+                let expected = overlapping.iter().map(|(start, end)| found(*start, *end, &text[*start..*end])).collect::<Vec<_>>();
+                assert_eq!(scan(&dawg, text, &OVERLAPPING), expected);
+
+                let mut leftmost = walk(&dawg, text);
+                leftmost.sort_by_key(|(start, end)| (*start, Reverse(*end)));
+                let mut expected: Vec<ScanMatch> = vec![];
+                for (start, end) in leftmost {
+                    if expected.last().map_or(true, |last| last.end <= start) {
+                        expected.push(found(start, end, &text[start..end]));
+                    }
+                }
+                assert_eq!(scan(&dawg, text, &ScanOptions::default()), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn should_yield_the_occurrences_while_reading_the_text() {
+        let mut dawg = setup_dawg();
+        dawg.build_scanner();
+
+        let text = format!("HIS CAT{}", "Z".repeat(10_000));
+        let mut matches = dawg.scan(&text, &ScanOptions { kind: MatchKind::LeftmostLongest, whole_words: true });
+        assert_eq!(matches.next(), Some(found(0, 3, "HIS")));
+        // the automaton has not gone further than the letter after the word
+        assert_eq!(matches.bytes, 4);
+        assert_eq!(matches.next(), None);
+    }
+
+    #[test]
+    fn should_measure_the_occurrences_in_the_text_as_given() {
+        let mut dawg = Dawg::with_normalization(Normalization::Nfd);
+        dawg.insert("\u{C9}T\u{C9}".to_string());
+        dawg.finish();
+
+        // "ÉTÉ" is stored with combining accents (7 bytes), but is 5 bytes in the text
+        let text = "L'\u{C9}T\u{C9}!";
+        let matches = scan(&dawg, text, &ScanOptions::default());
+        assert_eq!(matches, vec![found(2, 7, "E\u{301}TE\u{301}")]);
+        assert_eq!(&text[matches[0].start..matches[0].end], "\u{C9}T\u{C9}");
+
+        // the ligature is two letters once normalized, no word can start or end between them: only "fi" is found in it
+        let mut dawg = Dawg::with_normalization(Normalization::Nfkc);
+        for word in ["fi", "i", "in"] {
+            dawg.insert(word.to_string());
+        }
+        dawg.finish();
+
+        assert_eq!(scan(&dawg, "\u{FB01}n fin", &OVERLAPPING), vec![
+            found(0, 3, "fi"),
+            found(5, 7, "fi"),
+            found(6, 7, "i"),
+            found(6, 8, "in"),
+        ]);
+    }
+
+    #[test]
+    fn should_read_the_text_one_tile_at_a_time() {
+        let mut dawg = Dawg::with_tokenizer(Tiles::new(["LL"]));
+        let mut words = ["A", "L", "LLAMA"].map(String::from);
+        dawg.sort_words(&mut words);
+        for word in words {
+            dawg.insert(word);
+        }
+        dawg.finish();
+
+        // "LL" is a single tile, the text has no "L"
+        let words = scan(&dawg, "LLAMAS", &OVERLAPPING).into_iter().map(|found| found.word).collect::<Vec<_>>();
+        assert_eq!(words, vec!["A", "LLAMA", "A"]);
+    }
+}
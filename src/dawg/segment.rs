@@ -0,0 +1,215 @@
+use std::cmp::Ordering;
+
+use crate::dawg::dawg::Dawg;
+
+#[cfg(test)]
+#[path = "./segment.test.rs"]
+mod segment_test;
+
+/// How [`Segmenter::best`] ranks the segmentations of a text (the ones with the fewest unknown letters always come first)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scoring {
+    /// The segmentation with the fewest words e.g. "NOWHERE" -> "NOWHERE" rather than "NOW HERE"
+    FewestWords,
+    /// The most likely segmentation, where the score of a word (see [`Dawg::insert_with_score`]) is its frequency
+    Frequency,
+}
+
+/// A part of a segmented text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Segment {
+    /// A word of the lexicon
+    Word(String),
+    /// Letters that no word of the lexicon covers
+    Unknown(String),
+}
+
+/// Splits unspaced text (hashtags, domain names...) into the words of a dawg, returned by [`Dawg::segmenter`]
+///
+/// ```rust
+/// use dawg::{Dawg, Scoring, Segment};
+///
+/// let mut lexicon = Dawg::new();
+/// for word in ["A", "IS", "TEST", "THIS"] {
+///     lexicon.insert(word.to_string());
+/// }
+/// lexicon.finish();
+///
+/// let segmenter = lexicon.segmenter(Scoring::FewestWords);
+/// let words = vec![Segment::Word("THIS".to_string()), Segment::Word("IS".to_string()), Segment::Word("A".to_string())];
+/// assert_eq!(segmenter.best("THISISA"), words);
+///
+/// let words = vec![Segment::Word("A".to_string()), Segment::Unknown("XY".to_string()), Segment::Word("TEST".to_string())];
+/// assert_eq!(segmenter.best("AXYTEST"), words);
+/// ```
+#[derive(Debug)]
+pub struct Segmenter<'a> {
+    dawg: &'a Dawg,
+    scoring: Scoring,
+    /// the log of the sum of the frequencies of all the words (each one counted once more, so that a score of 0 is not impossible)
+    total: f64,
+}
+
+/// A step from a letter of the text to a later one
+#[derive(Debug, Clone, Copy)]
+enum Step {
+    /// the word ending before this letter, with its score
+    Word(usize, u64),
+    /// the letter is not part of any word
+    Unknown,
+}
+
+impl<'a> Segmenter<'a> {
+    pub(crate) fn new(dawg: &'a Dawg, scoring: Scoring) -> Self {
+        let root = dawg.get_root();
+
+        #[cfg(not(feature = "threading"))]
+        let count = root.borrow().count;
+        #[cfg(feature = "threading")]
+        let count = root.lock().unwrap().count;
+
+        // added up in f64, the scores of a large lexicon can add up to more than a u64 holds
+        let total = dawg.scores.iter().map(|score| *score as f64).sum::<f64>() + count as f64;
+        Self { dawg, scoring, total: total.ln() }
+    }
+
+    /// The words starting at each letter of the text (as steps to where they end), from the shortest to the longest
+    fn steps(&self, letters: &[String]) -> Vec<Vec<Step>> {
+        // the letters are already normalized and split, the text they make up is split back into them
+        let text = letters.concat();
+        let mut offset = 0;
+
+        letters.iter().enumerate().map(|(start, letter)| {
+            let steps = self.dawg.prefixes_of(&text[offset..]).map(|found| {
+                let score = self.dawg.score(&found.word).unwrap_or(0);
+                Step::Word(start + found.tokens, score)
+            }).collect();
+
+            offset += letter.len();
+            steps
+        }).collect()
+    }
+
+    /// The fewest unknown letters left from each letter of the text to its end
+    fn unknowns(steps: &[Vec<Step>]) -> Vec<usize> {
+        let mut unknowns = vec![0; steps.len() + 1];
+
+        for start in (0..steps.len()).rev() {
+            unknowns[start] = steps[start].iter().fold(unknowns[start + 1] + 1, |fewest, step| match step {
+                Step::Word(end, _) => fewest.min(unknowns[*end]),
+                Step::Unknown => fewest,
+            });
+        }
+
+        unknowns
+    }
+
+    /// The cost of adding a word with this score to a segmentation, the lower the better
+    fn cost(&self, score: u64) -> f64 {
+        match self.scoring {
+            Scoring::FewestWords => 1.0,
+            Scoring::Frequency => self.total - (score as f64 + 1.0).ln(),
+        }
+    }
+
+    /// Turns the steps taken through the letters of the text into segments, joining consecutive unknown letters
+    fn segments(letters: &[String], path: &[(usize, Step)]) -> Vec<Segment> {
+        let mut segments: Vec<Segment> = vec![];
+
+        for (start, step) in path {
+            match (step, segments.last_mut()) {
+                (Step::Word(end, _), _) => segments.push(Segment::Word(letters[*start..*end].concat())),
+                (Step::Unknown, Some(Segment::Unknown(unknown))) => unknown.push_str(&letters[*start]),
+                (Step::Unknown, _) => segments.push(Segment::Unknown(letters[*start].to_owned())),
+            }
+        }
+
+        segments
+    }
+
+    /// Returns the best segmentation of `text`: the one with the fewest unknown letters, then the best by the scoring
+    /// On a tie, the segmentation with the longest first word is returned
+    pub fn best(&self, text: impl AsRef<str>) -> Vec<Segment> {
+        let letters = self.dawg.tokenize(text);
+        let steps = self.steps(&letters);
+
+        // the best (unknown letters, cost) from each letter to the end of the text, and the step taken to get there
+        let mut costs = vec![(0, 0.0); letters.len() + 1];
+        let mut taken = vec![Step::Unknown; letters.len()];
+
+        for start in (0..letters.len()).rev() {
+            let (unknown, cost) = costs[start + 1];
+            costs[start] = (unknown + 1, cost);
+
+            for step in steps[start].iter().rev() {
+                let Step::Word(end, score) = step else { continue };
+                let candidate = (costs[*end].0, costs[*end].1 + self.cost(*score));
+                let order = candidate.0.cmp(&costs[start].0).then(candidate.1.total_cmp(&costs[start].1));
+
+                if order == Ordering::Less {
+                    costs[start] = candidate;
+                    taken[start] = *step;
+                }
+            }
+        }
+
+        let mut path = vec![];
+        let mut start = 0;
+        while start < letters.len() {
+            path.push((start, taken[start]));
+            start = match taken[start] {
+                Step::Word(end, _) => end,
+                Step::Unknown => start + 1,
+            };
+        }
+
+        Self::segments(&letters, &path)
+    }
+
+    /// Returns every segmentation of `text` with the fewest unknown letters (only the ones made of words, if there are any),
+    /// those starting with shorter words first
+    pub fn all(&self, text: impl AsRef<str>) -> Vec<Vec<Segment>> {
+        let letters = self.dawg.tokenize(text);
+        let mut steps = self.steps(&letters);
+        let unknowns = Self::unknowns(&steps);
+
+        // only keep the steps on a path with the fewest unknown letters
+        for (start, options) in steps.iter_mut().enumerate() {
+            options.retain(|step| matches!(step, Step::Word(end, _) if unknowns[*end] == unknowns[start]));
+            if unknowns[start + 1] + 1 == unknowns[start] {
+                options.push(Step::Unknown);
+            }
+        }
+
+        let mut found = vec![];
+        let mut stack = vec![(0, vec![])];
+
+        while let Some((start, path)) = stack.pop() {
+            if start == letters.len() {
+                found.push(Self::segments(&letters, &path));
+                continue;
+            }
+
+            // pushed in reverse, so that the shortest word is the first one out of the stack
+            for step in steps[start].iter().rev() {
+                let end = match step {
+                    Step::Word(end, _) => *end,
+                    Step::Unknown => start + 1,
+                };
+                let mut path = path.clone();
+                path.push((start, *step));
+                stack.push((end, path));
+            }
+        }
+
+        found
+    }
+}
+
+impl Dawg {
+    /// Returns a segmenter that splits unspaced text into the words of the lexicon (see [`Segmenter`])
+    /// The segmentation is case sensitive, and the dawg should be finished
+    pub fn segmenter(&self, scoring: Scoring) -> Segmenter<'_> {
+        Segmenter::new(self, scoring)
+    }
+}
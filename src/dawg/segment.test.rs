@@ -0,0 +1,82 @@
+#[cfg(test)]
+mod test_segment {
+    use crate::dawg::dawg::Dawg;
+    use crate::dawg::segment::{Scoring, Segment};
+
+    fn setup_dawg() -> Dawg {
+        let mut words = vec![("A", 50), ("HERE", 100), ("IS", 80), ("NOW", 100), ("NOWHERE", 1), ("TEST", 20), ("THIS", 60), ("ÒYÀ", 5)];
+        words.sort();
+
+        let mut dawg = Dawg::new();
+        for (word, score) in words {
+            dawg.insert_with_score(word.to_string(), score);
+        }
+        dawg.finish();
+        dawg
+    }
+
+    fn word(word: &str) -> Segment {
+        Segment::Word(word.to_string())
+    }
+
+    fn unknown(letters: &str) -> Segment {
+        Segment::Unknown(letters.to_string())
+    }
+
+    #[test]
+    fn should_split_a_text_into_words() {
+        let dawg = setup_dawg();
+        let segmenter = dawg.segmenter(Scoring::FewestWords);
+
+        assert_eq!(segmenter.best("THISISATEST"), vec![word("THIS"), word("IS"), word("A"), word("TEST")]);
+        assert_eq!(segmenter.best("ÒYÀA"), vec![word("ÒYÀ"), word("A")]);
+        assert!(segmenter.best("").is_empty());
+    }
+
+    #[test]
+    fn should_rank_segmentations_by_the_scoring() {
+        let dawg = setup_dawg();
+
+        assert_eq!(dawg.segmenter(Scoring::FewestWords).best("NOWHERE"), vec![word("NOWHERE")]);
+        // NOWHERE is a lot less frequent than NOW and HERE
+        assert_eq!(dawg.segmenter(Scoring::Frequency).best("NOWHERE"), vec![word("NOW"), word("HERE")]);
+    }
+
+    #[test]
+    fn should_report_unknown_letters() {
+        let dawg = setup_dawg();
+        let segmenter = dawg.segmenter(Scoring::Frequency);
+
+        assert_eq!(segmenter.best("THISXYZTEST"), vec![word("THIS"), unknown("XYZ"), word("TEST")]);
+        assert_eq!(segmenter.best("QTESTQ"), vec![unknown("Q"), word("TEST"), unknown("Q")]);
+        assert_eq!(segmenter.best("XYZ"), vec![unknown("XYZ")]);
+    }
+
+    #[test]
+    fn should_return_every_segmentation() {
+        let dawg = setup_dawg();
+        let segmenter = dawg.segmenter(Scoring::FewestWords);
+
+        assert_eq!(segmenter.all("NOWHEREISATEST"), vec![
+            vec![word("NOW"), word("HERE"), word("IS"), word("A"), word("TEST")],
+            vec![word("NOWHERE"), word("IS"), word("A"), word("TEST")],
+        ]);
+        // the segmentations with an unknown letter are only returned when there is no other
+        assert_eq!(segmenter.all("NOWHEREX"), vec![
+            vec![word("NOW"), word("HERE"), unknown("X")],
+            vec![word("NOWHERE"), unknown("X")],
+        ]);
+        assert_eq!(segmenter.all("AQA"), vec![vec![word("A"), unknown("Q"), word("A")]]);
+        assert_eq!(segmenter.all(""), vec![vec![]]);
+    }
+
+    #[test]
+    fn should_not_overflow_with_the_highest_scores() {
+        let mut dawg = Dawg::new();
+        dawg.insert_with_score("A".to_string(), u64::MAX);
+        dawg.insert_with_score("B".to_string(), u64::MAX);
+        dawg.finish();
+
+        assert_eq!(dawg.segmenter(Scoring::Frequency).best("AB"), vec![word("A"), word("B")]);
+    }
+}
@@ -0,0 +1,120 @@
+use std::collections::HashMap;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::dawg::dawg::Dawg;
+use crate::node::node::Node;
+
+#[cfg(test)]
+#[path = "./shape.test.rs"]
+mod shape_test;
+
+/// The placeholder for an unknown letter in the `known` argument of [`Dawg::match_shape`]
+pub const UNKNOWN_LETTER: &str = ".";
+
+/// A search for the words matching a letter shape, with the letters bound to the shape so far (in both directions)
+struct ShapeSearch<'a> {
+    dawg: &'a Dawg,
+    shape: Vec<&'a str>,
+    known: Vec<Option<String>>,
+    /// shape symbol -> letter
+    bindings: HashMap<&'a str, String>,
+    /// letter -> shape symbol
+    bound: HashMap<String, &'a str>,
+    found: Vec<String>,
+}
+
+impl<'a> ShapeSearch<'a> {
+    fn visit(&mut self, node: &Node, word: &mut String, index: usize) {
+        #[cfg(not(feature = "threading"))]
+        let current = node.borrow();
+        #[cfg(feature = "threading")]
+        let current = node.lock().unwrap();
+
+        if !current.reaches_length(self.shape.len() - index) {
+            return;
+        }
+
+        if index == self.shape.len() {
+            self.found.push(word.to_owned());
+            return;
+        }
+
+        let symbol = self.shape[index];
+        for (letter, child) in self.dawg.sorted_edges(&current) {
+            if self.known[index].as_ref().is_some_and(|known| *known != letter) {
+                continue;
+            }
+
+            // a shape symbol always stands for the same letter, and different symbols for different letters
+            let is_new = match (self.bindings.get(symbol), self.bound.get(&letter)) {
+                (None, None) => true,
+                (Some(bound_letter), Some(bound_symbol)) if *bound_letter == letter && *bound_symbol == symbol => false,
+                _ => continue,
+            };
+
+            if is_new {
+                self.bindings.insert(symbol, letter.to_owned());
+                self.bound.insert(letter.to_owned(), symbol);
+            }
+
+            let length = word.len();
+            word.push_str(&letter);
+            self.visit(&child, word, index + 1);
+            word.truncate(length);
+
+            if is_new {
+                self.bindings.remove(symbol);
+                self.bound.remove(&letter);
+            }
+        }
+    }
+}
+
+impl Dawg {
+    /// Returns (in the order of the dawg) the words that have the same letter pattern as `shape` (cryptogram search)
+    /// i.e. the same letter wherever `shape` repeats a symbol, and different letters for different symbols,
+    /// so "ABCA" matches "THAT" and "EDGE" but not "THIS" or "TOOT"
+    ///
+    /// `known` optionally fixes some letters, it is either empty or as long as `shape` (in letters of the dawg, see [`Dawg::tokenize`]),
+    /// with [`UNKNOWN_LETTER`] (".") wherever the letter is unknown e.g. ".H.."
+    ///
+    /// ```rust
+    /// use dawg::Dawg;
+    ///
+    /// let mut words = vec!["THAT", "THIS", "TOOT", "EDGE", "SEES", "DEED", "NOON", "PEEP"];
+    /// words.sort();
+    ///
+    /// let mut lexicon = Dawg::new();
+    /// for word in words {
+    ///     lexicon.insert(word.to_string());
+    /// }
+    /// lexicon.finish();
+    ///
+    /// assert_eq!(lexicon.match_shape("ABCA", ""), vec!["EDGE", "THAT"]);
+    /// assert_eq!(lexicon.match_shape("XYYX", ""), vec!["DEED", "NOON", "PEEP", "SEES", "TOOT"]);
+    /// assert_eq!(lexicon.match_shape("XYYX", ".E.."), vec!["DEED", "PEEP", "SEES"]);
+    /// ```
+    pub fn match_shape(&self, shape: impl AsRef<str>, known: impl AsRef<str>) -> Vec<String> {
+        let shape = shape.as_ref().graphemes(true).collect::<Vec<_>>();
+        let known = self.tokenize(known).into_iter()
+            .map(|letter| if letter == UNKNOWN_LETTER { None } else { Some(letter) })
+            .collect::<Vec<_>>();
+
+        if !known.is_empty() && known.len() != shape.len() {
+            return vec![];
+        }
+
+        let mut search = ShapeSearch {
+            dawg: self,
+            known: if known.is_empty() { vec![None; shape.len()] } else { known },
+            shape,
+            bindings: HashMap::new(),
+            bound: HashMap::new(),
+            found: vec![],
+        };
+
+        search.visit(&self.get_root(), &mut String::new(), 0);
+        search.found
+    }
+}
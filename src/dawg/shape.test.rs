@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod test_shape {
+    use crate::dawg::collation::Alphabet;
+    use crate::dawg::dawg::Dawg;
+
+    fn setup_dawg() -> Dawg {
+        let mut words = vec![
+            "THAT", "THIS", "TOOT", "EDGE", "SEES", "DEED", "NOON", "PEEP", "ERROR", "MIRROR",
+            "ALL", "ADD", "ODD", "ABC", "ÒYÒ", "ÀYÀ", "ÒYA",
+        ];
+        words.sort();
+
+        let mut dawg = Dawg::new();
+        for word in words {
+            dawg.insert(word.to_string());
+        }
+        dawg.finish();
+        dawg
+    }
+
+    #[test]
+    fn should_match_repeated_letters() {
+        let dawg = setup_dawg();
+
+        assert_eq!(dawg.match_shape("ABB", ""), vec!["ADD", "ALL", "ODD"]);
+        assert_eq!(dawg.match_shape("ABCDD", ""), Vec::<String>::new());
+        assert_eq!(dawg.match_shape("ABCCDC", ""), vec!["MIRROR"]);
+        assert_eq!(dawg.match_shape("ABBCB", ""), vec!["ERROR"]);
+    }
+
+    #[test]
+    fn should_require_different_letters_for_different_symbols() {
+        let dawg = setup_dawg();
+
+        assert_eq!(dawg.match_shape("ABC", ""), vec!["ABC", "ÒYA"]);
+        assert_eq!(dawg.match_shape("ABCD", ""), vec!["THIS"]);
+        assert_eq!(dawg.match_shape("ABAB", ""), Vec::<String>::new());
+    }
+
+    #[test]
+    fn should_combine_the_shape_with_known_letters() {
+        let dawg = setup_dawg();
+
+        assert_eq!(dawg.match_shape("ABB", "O.."), vec!["ODD"]);
+        assert_eq!(dawg.match_shape("ABB", "..L"), vec!["ALL"]);
+        // the known letters contradict the shape
+        assert!(dawg.match_shape("ABB", ".DL").is_empty());
+        // the known letters do not have the same length as the shape
+        assert!(dawg.match_shape("ABB", "O.").is_empty());
+    }
+
+    #[test]
+    fn should_support_accented_letters() {
+        let dawg = setup_dawg();
+
+        assert_eq!(dawg.match_shape("ABA", ""), vec!["ÀYÀ", "ÒYÒ"]);
+        assert_eq!(dawg.match_shape("ABA", "Ò.."), vec!["ÒYÒ"]);
+    }
+
+    #[test]
+    fn should_return_the_words_in_the_order_of_the_collator() {
+        let mut dawg = Dawg::with_collator(Alphabet::spanish());
+        let mut words = ["MAZO", "MAÑO", "MANO"].map(String::from);
+        dawg.sort_words(&mut words);
+        for word in words {
+            dawg.insert(word);
+        }
+        dawg.finish();
+
+        assert_eq!(dawg.match_shape("ABCD", ""), vec!["MANO", "MAÑO", "MAZO"]);
+    }
+}
@@ -0,0 +1,180 @@
+use std::cmp::Reverse;
+use std::collections::HashSet;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::dawg::dawg::Dawg;
+use crate::dawg::ladder::Distance;
+
+#[cfg(test)]
+#[path = "./spell.test.rs"]
+mod spell_test;
+
+/// How the case of a word in a text may differ from the lexicon (see [`Dawg::check_text`])
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaseHandling {
+    /// A word must be written exactly as in the lexicon
+    Exact,
+    /// A word may also be capitalized (e.g. at the start of a sentence) or in capitals: "Hello" and "HELLO" for "hello", "PARIS" for "Paris", but not "paris"
+    Capitalized,
+    /// A word may be written in any case
+    Ignore,
+}
+
+/// How [`Dawg::check_text_with`] checks a text
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpellOptions {
+    pub case: CaseHandling,
+    /// The most suggestions returned for a misspelled word
+    pub max_suggestions: usize,
+}
+
+impl Default for SpellOptions {
+    /// Capitalized words accepted, and up to 5 suggestions
+    fn default() -> Self {
+        Self { case: CaseHandling::Capitalized, max_suggestions: 5 }
+    }
+}
+
+/// A word of a text that is not in the lexicon, see [`Dawg::check_text`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Misspelling {
+    /// where the word starts in the text, in bytes
+    pub start: usize,
+    /// where the word ends in the text (exclusive), in bytes
+    pub end: usize,
+    pub word: String,
+    /// words of the lexicon one edit away, the highest scores first (see [`Dawg::insert_with_score`])
+    pub suggestions: Vec<String>,
+}
+
+/// Whether a part of the text between two spaces is a link or an email address, whose words are not checked
+fn is_address(chunk: &str) -> bool {
+    let chunk = chunk.trim_start_matches(|letter: char| !letter.is_alphanumeric());
+    let email = chunk.rsplit_once('@').is_some_and(|(user, domain)| !user.is_empty() && domain.contains('.'));
+
+    chunk.contains("://") || chunk.starts_with("www.") || email
+}
+
+/// Returns the parts of `text` between spaces, with where they start in bytes
+fn chunks(text: &str) -> Vec<(usize, &str)> {
+    let mut chunks = vec![];
+    let mut start = None;
+
+    for (index, letter) in text.char_indices().chain(std::iter::once((text.len(), ' '))) {
+        match (letter.is_whitespace(), start) {
+            (true, Some(from)) => {
+                chunks.push((from, &text[from..index]));
+                start = None;
+            }
+            (false, None) => start = Some(index),
+            _ => {}
+        }
+    }
+
+    chunks
+}
+
+/// Returns `word` with its first letter in capitals and the others in small letters
+fn capitalize(word: &str) -> String {
+    let mut letters = word.chars();
+    match letters.next() {
+        Some(first) => first.to_uppercase().chain(letters.flat_map(char::to_lowercase)).collect(),
+        None => String::new(),
+    }
+}
+
+impl Dawg {
+    /// Returns the ways `word` may be written in the lexicon, itself first
+    fn spellings(&self, word: &str, case: CaseHandling) -> Vec<String> {
+        let lower = word.to_lowercase();
+        let mut spellings = vec![word.to_string()];
+
+        match case {
+            CaseHandling::Exact => {}
+            CaseHandling::Capitalized => {
+                if word == capitalize(word) {
+                    spellings.push(lower);
+                } else if word == word.to_uppercase() {
+                    spellings.extend([lower, capitalize(word)]);
+                }
+            }
+            CaseHandling::Ignore => spellings.extend([lower, word.to_uppercase(), capitalize(word)]),
+        }
+
+        let mut seen = HashSet::new();
+        spellings.retain(|spelling| seen.insert(spelling.to_owned()));
+        spellings
+    }
+
+    /// Returns the words of the lexicon one edit away from any spelling of `word`, the highest scores first
+    fn suggestions(&self, spellings: &[String], max: usize) -> Vec<String> {
+        let mut suggestions = spellings.iter().flat_map(|spelling| self.neighbors(spelling, Distance::Levenshtein)).collect::<Vec<_>>();
+        self.sort_words(&mut suggestions);
+        suggestions.dedup();
+        suggestions.sort_by_key(|suggestion| Reverse(self.score(suggestion).unwrap_or(0)));
+        suggestions.truncate(max);
+        suggestions
+    }
+
+    /// Returns the words of `text` that are not in the lexicon, with suggestions, using the default [`SpellOptions`] (see [`Dawg::check_text_with`])
+    ///
+    /// ```rust
+    /// use dawg::Dawg;
+    ///
+    /// let mut words = vec!["a", "is", "test", "this"];
+    /// words.sort();
+    ///
+    /// let mut lexicon = Dawg::new();
+    /// for word in words {
+    ///     lexicon.insert(word.to_string());
+    /// }
+    /// lexicon.finish();
+    ///
+    /// let text = "This is a tesst, see https://example.com/tset or mail me@example.com 42 times";
+    /// let misspellings = lexicon.check_text(text);
+    ///
+    /// assert_eq!(misspellings.iter().map(|found| found.word.as_str()).collect::<Vec<_>>(), vec!["tesst", "see", "or", "mail", "times"]);
+    /// assert_eq!(&text[misspellings[0].start..misspellings[0].end], "tesst");
+    /// assert_eq!(misspellings[0].suggestions, vec!["test"]);
+    /// ```
+    pub fn check_text(&self, text: impl AsRef<str>) -> Vec<Misspelling> {
+        self.check_text_with(text, &SpellOptions::default())
+    }
+
+    /// Returns the words of `text` that are not in the lexicon, in the order of the text, with where they are (in bytes) and suggestions.
+    /// Words are found at unicode word boundaries, words with digits (numbers, "3rd"...), links and email addresses are skipped
+    pub fn check_text_with(&self, text: impl AsRef<str>, options: &SpellOptions) -> Vec<Misspelling> {
+        let mut misspellings = vec![];
+
+        for (offset, chunk) in chunks(text.as_ref()) {
+            if is_address(chunk) {
+                continue;
+            }
+
+            for (start, word) in chunk.split_word_bound_indices() {
+                let is_word = word.chars().any(char::is_alphabetic) && !word.chars().any(|letter| letter.is_numeric());
+                if !is_word {
+                    continue;
+                }
+
+                let spellings = self.spellings(word, options.case);
+                let known = match options.case {
+                    CaseHandling::Ignore => self.is_word(word, false).is_some(),
+                    _ => spellings.iter().any(|spelling| self.is_word(spelling, true).is_some()),
+                };
+
+                if !known {
+                    misspellings.push(Misspelling {
+                        start: offset + start,
+                        end: offset + start + word.len(),
+                        word: word.to_string(),
+                        suggestions: self.suggestions(&spellings, options.max_suggestions),
+                    });
+                }
+            }
+        }
+
+        misspellings
+    }
+}
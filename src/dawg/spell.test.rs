@@ -0,0 +1,70 @@
+#[cfg(test)]
+mod test_spell {
+    use crate::dawg::dawg::Dawg;
+    use crate::dawg::spell::{CaseHandling, Misspelling, SpellOptions};
+
+    fn setup_dawg() -> Dawg {
+        let mut words = vec![("Paris", 10), ("a", 50), ("cat", 30), ("cart", 5), ("car", 40), ("don't", 5), ("in", 60), ("is", 60), ("the", 90), ("òyà", 1)];
+        words.sort();
+
+        let mut dawg = Dawg::new();
+        for (word, score) in words {
+            dawg.insert_with_score(word.to_string(), score);
+        }
+        dawg.finish();
+        dawg
+    }
+
+    fn words(misspellings: &[Misspelling]) -> Vec<&str> {
+        misspellings.iter().map(|found| found.word.as_str()).collect()
+    }
+
+    #[test]
+    fn should_return_the_misspelled_words_with_their_offsets() {
+        let dawg = setup_dawg();
+        let text = "The caat is in Pariss, don't òyàa!";
+        let misspellings = dawg.check_text(text);
+
+        assert_eq!(words(&misspellings), vec!["caat", "Pariss", "òyàa"]);
+        for found in &misspellings {
+            assert_eq!(&text[found.start..found.end], found.word);
+        }
+        assert_eq!(misspellings[2].start, 29);
+        assert!(dawg.check_text("").is_empty());
+        assert!(dawg.check_text("the cat is in Paris").is_empty());
+    }
+
+    #[test]
+    fn should_suggest_the_most_frequent_words_first() {
+        let dawg = setup_dawg();
+
+        let misspellings = dawg.check_text("caat cadt Pariss");
+        assert_eq!(misspellings[0].suggestions, vec!["cat", "cart"]);
+        assert_eq!(misspellings[1].suggestions, vec!["cat", "cart"]);
+        assert_eq!(misspellings[2].suggestions, vec!["Paris"]);
+
+        let options = SpellOptions { max_suggestions: 1, ..SpellOptions::default() };
+        assert_eq!(dawg.check_text_with("cadt", &options)[0].suggestions, vec!["cat"]);
+        // a capitalized word is corrected from its lowercase spelling too
+        assert_eq!(dawg.check_text("Caat")[0].suggestions, vec!["cat", "cart"]);
+    }
+
+    #[test]
+    fn should_skip_numbers_links_and_emails() {
+        let dawg = setup_dawg();
+        let text = "the 2 cats in 3rd, 4.5 www.thecaat.com (https://caat.org/xyz) cat@caat.org";
+
+        assert_eq!(words(&dawg.check_text(text)), vec!["cats"]);
+    }
+
+    #[test]
+    fn should_follow_the_case_handling() {
+        let dawg = setup_dawg();
+        let text = "The CAT is in paris, PARIS and pArIs";
+        let check = |case| words(&dawg.check_text_with(text, &SpellOptions { case, ..SpellOptions::default() })).into_iter().map(str::to_string).collect::<Vec<_>>();
+
+        assert_eq!(check(CaseHandling::Exact), vec!["The", "CAT", "paris", "PARIS", "and", "pArIs"]);
+        assert_eq!(check(CaseHandling::Capitalized), vec!["paris", "and", "pArIs"]);
+        assert_eq!(check(CaseHandling::Ignore), vec!["and"]);
+    }
+}
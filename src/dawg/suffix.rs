@@ -0,0 +1,138 @@
+use serde::{Deserialize, Serialize};
+
+use crate::dawg::collation::Collator;
+use crate::dawg::dawg::Dawg;
+use crate::dawg::symbol::Tokenizer;
+
+#[cfg(test)]
+#[path = "./suffix.test.rs"]
+mod suffix_test;
+
+/// A lexicon that answers "ends with" queries as efficiently as "starts with" ones
+/// It holds the usual dawg and a companion dawg of the reversed words, both built when calling [`SuffixDawg::finish`]
+///
+/// ```rust
+/// use dawg::SuffixDawg;
+///
+/// let mut words = vec!["ACTION", "NATION", "NATIONAL", "NOTE", "STATION"];
+/// words.sort();
+///
+/// let mut lexicon = SuffixDawg::new();
+/// for word in words {
+///     lexicon.insert(word.to_string());
+/// }
+/// lexicon.finish();
+///
+/// assert_eq!(lexicon.ends_with("TION"), vec!["ACTION", "NATION", "STATION"]);
+/// assert_eq!(lexicon.starts_and_ends_with("N", "TION"), vec!["NATION"]);
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SuffixDawg {
+    forward: Dawg,
+    reversed: Dawg,
+    /// the letters of the words inserted so far, reversed. They can only be sorted (and inserted) once all the words are known
+    pending: Vec<Vec<String>>,
+}
+
+impl SuffixDawg {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a lexicon that splits words with `tokenizer` (see [`Dawg::with_tokenizer`]), words are reversed tile by tile
+    pub fn with_tokenizer(tokenizer: impl Tokenizer<String> + Send + Sync + 'static) -> Self {
+        Self { forward: Dawg::with_tokenizer(tokenizer), ..Self::default() }
+    }
+
+    /// Creates a lexicon whose words are ordered by `collator` (see [`Dawg::with_collator`])
+    pub fn with_collator(collator: impl Collator<String> + Send + Sync + 'static) -> Self {
+        Self { forward: Dawg::with_collator(collator), ..Self::default() }
+    }
+
+    /// Splits `word` into the letters of the lexicon (see [`Dawg::tokenize`]), last letter first
+    fn reverse(&self, word: impl AsRef<str>) -> Vec<String> {
+        let mut letters = self.forward.tokenize(word);
+        letters.reverse();
+        letters
+    }
+
+    /// Turns a sequence of the reversed dawg back into a word
+    fn unreverse(mut letters: Vec<String>) -> String {
+        letters.reverse();
+        letters.concat()
+    }
+
+    /// Adds a word into the lexicon
+    /// Just like [`Dawg::insert`], words are expected to be sorted (see [`Dawg::sort_words`]) before insertion
+    pub fn insert(&mut self, word: String) {
+        self.pending.push(self.reverse(&word));
+        self.forward.insert(word);
+    }
+
+    /// Closes the lexicon after all the words have been inserted, building the reversed dawg
+    pub fn finish(&mut self) {
+        let mut pending = std::mem::take(&mut self.pending);
+        pending.sort();
+        pending.dedup();
+
+        // the reversed words are stored letter by letter, so they are never split again
+        for letters in pending {
+            self.reversed.insert_sequence(letters);
+        }
+
+        self.forward.finish();
+        self.reversed.finish();
+    }
+
+    /// The dawg of the (non reversed) words, for every other query
+    pub fn dawg(&self) -> &Dawg {
+        &self.forward
+    }
+
+    /// Returns the words starting with `prefix`, in the order of the dawg
+    pub fn starts_with(&self, prefix: impl AsRef<str>) -> Vec<String> {
+        self.forward.words_with_prefix(prefix).collect()
+    }
+
+    /// Returns the words ending with `suffix`, in the order of the dawg
+    pub fn ends_with(&self, suffix: impl AsRef<str>) -> Vec<String> {
+        let mut words = self.reversed.sequences_with_prefix(&self.reverse(suffix))
+            .map(Self::unreverse)
+            .collect::<Vec<_>>();
+
+        self.forward.sort_words(&mut words);
+        words
+    }
+
+    /// Returns the words that start with `prefix` and end with `suffix`, in the order of the dawg
+    /// Only the smaller of the two sets is walked (their sizes are known from the word count of each node), the other condition is then checked on each word
+    pub fn starts_and_ends_with(&self, prefix: impl AsRef<str>, suffix: impl AsRef<str>) -> Vec<String> {
+        let (prefix, reversed) = (self.forward.tokenize(prefix), self.reverse(suffix));
+
+        let (Some(starting), Some(ending)) = (self.forward.lookup_sequence(&prefix), self.reversed.lookup_sequence(&reversed)) else {
+            return vec![];
+        };
+
+        #[cfg(not(feature = "threading"))]
+        let (starting, ending) = (starting.borrow().count, ending.borrow().count);
+        #[cfg(feature = "threading")]
+        let (starting, ending) = (starting.lock().unwrap().count, ending.lock().unwrap().count);
+
+        if starting <= ending {
+            let suffix = reversed.into_iter().rev().collect::<Vec<_>>();
+            return self.forward.sequences_with_prefix(&prefix)
+                .filter(|letters| letters.ends_with(&suffix))
+                .map(|letters| letters.concat())
+                .collect();
+        }
+
+        let prefix = prefix.into_iter().rev().collect::<Vec<_>>();
+        let mut words = self.reversed.sequences_with_prefix(&reversed)
+            .filter(|letters| letters.ends_with(&prefix))
+            .map(Self::unreverse)
+            .collect::<Vec<_>>();
+
+        self.forward.sort_words(&mut words);
+        words
+    }
+}
@@ -0,0 +1,88 @@
+#[cfg(test)]
+mod test_suffix {
+    use crate::dawg::collation::Alphabet;
+    use crate::dawg::suffix::SuffixDawg;
+    use crate::dawg::symbol::Tiles;
+
+    fn setup_dawg() -> SuffixDawg {
+        let mut words = vec![
+            "ACTION", "CATION", "NATION", "NATIONAL", "NOTATION", "NOTE", "STATION", "STATIONS", "TION",
+            "AYÒ", "ÒYÒ", "ÒYA",
+        ];
+        words.sort();
+
+        let mut dawg = SuffixDawg::new();
+        for word in words {
+            dawg.insert(word.to_string());
+        }
+        dawg.finish();
+        dawg
+    }
+
+    #[test]
+    fn should_return_the_words_ending_with_a_suffix() {
+        let dawg = setup_dawg();
+
+        assert_eq!(dawg.ends_with("TION"), vec!["ACTION", "CATION", "NATION", "NOTATION", "STATION", "TION"]);
+        assert_eq!(dawg.ends_with("ONS"), vec!["STATIONS"]);
+        assert_eq!(dawg.ends_with("YÒ"), vec!["AYÒ", "ÒYÒ"]);
+        assert!(dawg.ends_with("XYZ").is_empty());
+        assert_eq!(dawg.ends_with("").len(), 12);
+    }
+
+    #[test]
+    fn should_return_the_words_starting_with_a_prefix() {
+        let dawg = setup_dawg();
+
+        assert_eq!(dawg.starts_with("NOT"), vec!["NOTATION", "NOTE"]);
+        assert!(dawg.dawg().is_word("NATIONAL", true).is_some());
+    }
+
+    #[test]
+    fn should_return_the_words_with_both_a_prefix_and_a_suffix() {
+        let dawg = setup_dawg();
+
+        assert_eq!(dawg.starts_and_ends_with("N", "TION"), vec!["NATION", "NOTATION"]);
+        assert_eq!(dawg.starts_and_ends_with("NOTA", "ION"), vec!["NOTATION"]);
+        assert_eq!(dawg.starts_and_ends_with("", "TIONS"), vec!["STATIONS"]);
+        assert_eq!(dawg.starts_and_ends_with("S", ""), vec!["STATION", "STATIONS"]);
+        // the prefix and the suffix can overlap
+        assert_eq!(dawg.starts_and_ends_with("TI", "ION"), vec!["TION"]);
+        assert!(dawg.starts_and_ends_with("X", "TION").is_empty());
+        assert!(dawg.starts_and_ends_with("N", "X").is_empty());
+    }
+
+    #[test]
+    fn should_reverse_words_tile_by_tile() {
+        let mut dawg = SuffixDawg::with_tokenizer(Tiles::new(["CH", "LL"]));
+        let mut words = ["CALL", "CHAL", "LLAMA", "PALL", "MALLA"].map(String::from);
+        dawg.dawg().sort_words(&mut words);
+        for word in words {
+            dawg.insert(word);
+        }
+        dawg.finish();
+
+        assert_eq!(dawg.ends_with("LL"), vec!["CALL", "PALL"]);
+        // "L" is not the last letter of "CALL", it is part of the "LL" tile
+        assert_eq!(dawg.ends_with("L"), vec!["CHAL"]);
+        assert_eq!(dawg.ends_with("LLA"), vec!["MALLA"]);
+        assert_eq!(dawg.starts_and_ends_with("CH", "L"), vec!["CHAL"]);
+        assert!(dawg.starts_and_ends_with("C", "L").is_empty());
+        assert_eq!(dawg.starts_and_ends_with("LL", "A"), vec!["LLAMA"]);
+    }
+
+    #[test]
+    fn should_return_the_words_in_the_order_of_the_collator() {
+        let mut dawg = SuffixDawg::with_collator(Alphabet::spanish());
+        let mut words = ["MAZO", "MAÑO", "MANO", "NO"].map(String::from);
+        dawg.dawg().sort_words(&mut words);
+        for word in words {
+            dawg.insert(word);
+        }
+        dawg.finish();
+
+        assert_eq!(dawg.ends_with("O"), vec!["MANO", "MAÑO", "MAZO", "NO"]);
+        assert_eq!(dawg.starts_and_ends_with("MA", "O"), vec!["MANO", "MAÑO", "MAZO"]);
+        assert_eq!(dawg.starts_and_ends_with("M", "NO"), vec!["MANO"]);
+    }
+}
@@ -0,0 +1,127 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+#[cfg(test)]
+#[path = "./symbol.test.rs"]
+mod symbol_test;
+
+/// A symbol on the edges of a [`Dawg`](crate::Dawg), i.e. what the words of the lexicon are made of
+/// Implemented for any type that can be hashed, ordered and cloned, e.g. `String` (graphemes), `char`, `u8` or `u32` (token ids)
+pub trait Symbol: Clone + Eq + Hash + Ord + Debug {}
+
+impl<T: Clone + Eq + Hash + Ord + Debug> Symbol for T {}
+
+/// Splits a text into the symbols a dawg is built from
+/// A `Dawg<String>` can be given its own tokenizer with [`Dawg::with_tokenizer`](crate::Dawg::with_tokenizer), [`Graphemes`] is used otherwise
+pub trait Tokenizer<S: Symbol>: Debug {
+    fn tokenize(&self, text: &str) -> Vec<S>;
+
+    /// Returns the length in bytes of the first unit of `text` that is split on its own (0 when `text` is empty),
+    /// so that a text can be read one piece at a time with the same result as [`Tokenizer::tokenize`] on the whole text.
+    /// The first grapheme by default, a tokenizer that joins graphemes must return the whole first symbol
+    fn first_len(&self, text: &str) -> usize {
+        text.graphemes(true).next().map_or(0, str::len)
+    }
+}
+
+/// Splits a text into its extended grapheme clusters (user perceived letters, e.g. "Ò" is one letter even when written with a combining mark)
+/// This is how `Dawg<String>` splits words by default
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Graphemes;
+
+impl Tokenizer<String> for Graphemes {
+    fn tokenize(&self, text: &str) -> Vec<String> {
+        text.graphemes(true).map(String::from).collect()
+    }
+}
+
+/// Splits a text into its unicode scalar values (`char`s)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Chars;
+
+impl Tokenizer<char> for Chars {
+    fn tokenize(&self, text: &str) -> Vec<char> {
+        text.chars().collect()
+    }
+}
+
+/// Splits a text into its (UTF-8) bytes
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Bytes;
+
+impl Tokenizer<u8> for Bytes {
+    fn tokenize(&self, text: &str) -> Vec<u8> {
+        text.bytes().collect()
+    }
+}
+
+/// Splits a text into tiles, where some tiles are made of more than one letter e.g. "CH", "LL" and "RR" in Spanish, or "IJ" in Dutch
+/// The longest tile matching at each position is taken (regardless of case), any other letter (grapheme) is a tile of its own
+///
+/// Tiles change the order of the words: a tile sorts after the single letters it starts with, so "LUNA" comes before "LLAMA".
+/// Words inserted into a dawg using tiles must be sorted with [`Dawg::sort_words`](crate::Dawg::sort_words), not `Vec::sort`
+///
+/// ```rust
+/// use dawg::{Tiles, Tokenizer};
+///
+/// let spanish = Tiles::new(["CH", "LL", "RR"]);
+/// assert_eq!(spanish.tokenize("LLAMAR"), vec!["LL", "A", "M", "A", "R"]);
+/// assert_eq!(spanish.tokenize("perro"), vec!["p", "e", "rr", "o"]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Tiles {
+    /// the multi-letter tiles (uppercased), each with its number of graphemes, longest first
+    tiles: Vec<(String, usize)>,
+}
+
+impl Tiles {
+    pub fn new<T: AsRef<str>>(tiles: impl IntoIterator<Item = T>) -> Self {
+        let mut tiles = tiles.into_iter()
+            .map(|tile| (tile.as_ref().to_uppercase(), tile.as_ref().graphemes(true).count()))
+            .filter(|(_, length)| *length > 1)
+            .collect::<Vec<_>>();
+        tiles.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        tiles.dedup();
+
+        Self { tiles }
+    }
+}
+
+impl Tiles {
+    /// The number of letters (graphemes) in the tile starting at `index`
+    fn tile_length(&self, letters: &[&str], index: usize) -> usize {
+        self.tiles.iter()
+            .filter(|(_, length)| index + length <= letters.len())
+            .find(|(tile, length)| letters[index..index + length].concat().to_uppercase() == *tile)
+            .map_or(1, |(_, length)| *length)
+    }
+}
+
+impl Tokenizer<String> for Tiles {
+    fn tokenize(&self, text: &str) -> Vec<String> {
+        let letters = text.graphemes(true).collect::<Vec<_>>();
+        let mut result = vec![];
+        let mut index = 0;
+
+        while index < letters.len() {
+            let length = self.tile_length(&letters, index);
+            result.push(letters[index..index + length].concat());
+            index += length;
+        }
+
+        result
+    }
+
+    fn first_len(&self, text: &str) -> usize {
+        // the tiles are sorted longest first, no tile needs more letters than the first one
+        let longest = self.tiles.first().map_or(1, |(_, length)| *length);
+        let letters = text.graphemes(true).take(longest).collect::<Vec<_>>();
+
+        match letters.is_empty() {
+            true => 0,
+            false => letters[..self.tile_length(&letters, 0)].iter().map(|letter| letter.len()).sum(),
+        }
+    }
+}
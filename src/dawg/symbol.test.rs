@@ -0,0 +1,125 @@
+#[cfg(test)]
+mod test_symbol {
+    use crate::dawg::dawg::Dawg;
+    use crate::dawg::symbol::{Bytes, Chars, Graphemes, Tiles, Tokenizer};
+
+    #[test]
+    fn should_split_text_with_each_tokenizer() {
+        assert_eq!(Graphemes.tokenize("ÒYA"), vec!["Ò", "Y", "A"]);
+        assert_eq!(Chars.tokenize("abc"), vec!['a', 'b', 'c']);
+        assert_eq!(Bytes.tokenize("Ò"), vec![0xC3, 0x92]);
+    }
+
+    #[test]
+    fn should_split_text_into_the_longest_matching_tiles() {
+        let spanish = Tiles::new(["CH", "LL", "RR"]);
+        assert_eq!(spanish.tokenize("CHURRO"), vec!["CH", "U", "RR", "O"]);
+        assert_eq!(spanish.tokenize("calle"), vec!["c", "a", "ll", "e"]);
+        assert_eq!(spanish.tokenize("AÑO"), vec!["A", "Ñ", "O"]);
+
+        // longer tiles win over the shorter ones they start with
+        let tiles = Tiles::new(["L", "LL", "LLL"]);
+        assert_eq!(tiles.tokenize("LLLLL"), vec!["LLL", "LL"]);
+
+        let dutch = Tiles::new(["IJ"]);
+        assert_eq!(dutch.tokenize("IJS"), vec!["IJ", "S"]);
+        assert_eq!(dutch.tokenize("I"), vec!["I"]);
+        assert!(dutch.tokenize("").is_empty());
+    }
+
+    #[test]
+    fn should_measure_the_first_symbol_of_a_text() {
+        assert_eq!(Graphemes.first_len("\u{D2}YA"), 2);
+        assert_eq!(Graphemes.first_len("O\u{300}YA"), 3);
+        assert_eq!(Graphemes.first_len(""), 0);
+
+        let spanish = Tiles::new(["CH", "LL", "RR"]);
+        assert_eq!(spanish.first_len("llama"), 2);
+        assert_eq!(spanish.first_len("LUNA"), 1);
+        assert_eq!(spanish.first_len("\u{D1}U"), 2);
+        assert_eq!(spanish.first_len(""), 0);
+    }
+
+    #[test]
+    fn should_use_the_tokenizer_everywhere_words_are_split() {
+        let mut words = ["CALLA", "CALLAR", "LLAMA", "LAMA", "CHAL", "CAL"].map(String::from);
+        words.sort();
+
+        let mut lexicon = Dawg::with_tokenizer(Tiles::new(["CH", "LL", "RR"]));
+        for word in words {
+            lexicon.insert(word);
+        }
+        lexicon.finish();
+
+        assert!(lexicon.is_word("calla", false).is_some());
+        assert!(lexicon.is_word("CHAL", true).is_some());
+        assert_eq!(lexicon.word_at(lexicon.rank("LLAMA").unwrap()), Some("LLAMA".to_string()));
+
+        assert_eq!(lexicon.find_anagrams("MALLA").collect::<Vec<_>>(), vec!["LLAMA"]);
+        // two "L" tiles do not make a "LL" tile
+        assert_eq!(lexicon.find_anagrams("MALAL").count(), 0);
+
+        // "LL" is a single letter, so CALLA and LLAMA have 4 letters like LAMA
+        assert_eq!(lexicon.match_shape("ABCB", ""), vec!["CALLA", "LAMA", "LLAMA"]);
+        assert_eq!(lexicon.match_shape("ABCB", "LL..."), vec!["LLAMA"]);
+    }
+
+    #[test]
+    fn should_store_byte_sequences() {
+        let mut kmers = ["ACGT", "ACGA", "TTGA", "ACG"].into_iter().map(|kmer| Bytes.tokenize(kmer)).collect::<Vec<_>>();
+        kmers.sort();
+
+        let mut dna: Dawg<u8> = Dawg::default();
+        for kmer in &kmers {
+            dna.insert_sequence(kmer.to_owned());
+        }
+        dna.finish();
+
+        for kmer in &kmers {
+            assert!(dna.contains_sequence(kmer));
+        }
+        assert!(!dna.contains_sequence(b"AC"));
+        assert!(!dna.contains_sequence(b"TTGAA"));
+        assert!(dna.lookup_sequence(b"TT").is_some());
+
+        assert_eq!(dna.sequences().collect::<Vec<_>>(), kmers);
+        assert_eq!(dna.sequences_with_prefix(b"ACG").collect::<Vec<_>>(), vec![b"ACG".to_vec(), b"ACGA".to_vec(), b"ACGT".to_vec()]);
+        assert_eq!(dna.sequences_with_prefix(b"G").count(), 0);
+    }
+
+    #[test]
+    fn should_store_char_sequences() {
+        let mut dawg: Dawg<char> = Dawg::default();
+        for word in ["bat", "bath", "cat"] {
+            dawg.insert_sequence(Chars.tokenize(word));
+        }
+        dawg.finish();
+
+        let words = dawg.sequences().map(|word| word.into_iter().collect::<String>()).collect::<Vec<_>>();
+        assert_eq!(words, vec!["bat", "bath", "cat"]);
+        assert!(dawg.contains_sequence(&['c', 'a', 't']));
+    }
+
+    #[test]
+    fn should_store_token_ids_and_merge_shared_suffixes() {
+        let sentences: Vec<Vec<u32>> = vec![vec![1, 2, 3], vec![1, 5, 3], vec![7, 2, 3], vec![7, 5, 3]];
+
+        let mut dawg: Dawg<u32> = Dawg::default();
+        for sentence in &sentences {
+            dawg.insert_sequence(sentence.to_owned());
+        }
+        dawg.finish();
+
+        assert_eq!(dawg.sequences().collect::<Vec<_>>(), sentences);
+        // root, {1, 7}, {2, 5}, {3}
+        assert_eq!(dawg.node_count(), 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_if_sequences_are_not_ordered() {
+        let mut dawg: Dawg<u32> = Dawg::default();
+        dawg.insert_sequence(vec![2, 1]);
+        dawg.insert_sequence(vec![1, 2]);
+    }
+}
@@ -1,19 +1,21 @@
 use serde::{Deserialize, Serialize};
 
+use crate::dawg::symbol::Symbol;
 use crate::node::node::Node;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct TriDawg {
+#[serde(bound(deserialize = "S: Symbol + Deserialize<'de>"))]
+pub struct TriDawg<S = String> {
     /// A node that extends to this node (parent)
-    pub parent: Node,
+    pub parent: Node<S>,
     /// the letter on this node
-    pub letter: String,
+    pub letter: S,
     /// The current node itself
-    pub child: Node,
+    pub child: Node<S>,
 }
 
-impl TriDawg {
-    pub fn new(parent: Node, letter: String, child: Node) -> Self {
+impl<S: Symbol> TriDawg<S> {
+    pub fn new(parent: Node<S>, letter: S, child: Node<S>) -> Self {
         Self {
             parent,
             letter,
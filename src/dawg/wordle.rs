@@ -0,0 +1,264 @@
+use std::collections::{HashMap, HashSet};
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::dawg::dawg::Dawg;
+use crate::node::node::Node;
+
+#[cfg(test)]
+#[path = "./wordle.test.rs"]
+mod wordle_test;
+
+/// The colour a letter of a guess gets in Wordle/Mastermind style games
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Feedback {
+    /// The letter is in the word and in this position
+    Green,
+    /// The letter is in the word but not in this position
+    Yellow,
+    /// The letter is not in the word (or not as many times as it was guessed)
+    Grey,
+}
+
+impl Feedback {
+    /// Returns the feedback a player gets for `guess` when the hidden word is `answer` (both are expected to have the same length)
+    /// Greens are assigned first, then yellows from left to right while the answer still has unmatched copies of the letter.
+    /// The words are split into graphemes, see [`Dawg::feedback`] for the letters (tiles) of a dawg
+    pub fn compute(guess: impl AsRef<str>, answer: impl AsRef<str>) -> Vec<Feedback> {
+        let guess = guess.as_ref().graphemes(true).collect::<Vec<_>>();
+        let answer = answer.as_ref().graphemes(true).collect::<Vec<_>>();
+
+        Self::compare(&guess, &answer)
+    }
+
+    /// Returns the feedback for a guess and an answer already split into letters
+    fn compare<T: AsRef<str>>(guess: &[T], answer: &[T]) -> Vec<Feedback> {
+        let mut result = vec![Feedback::Grey; guess.len()];
+        let mut unmatched: HashMap<&str, usize> = HashMap::new();
+
+        for (index, letter) in guess.iter().enumerate() {
+            match answer.get(index) {
+                Some(expected) if expected.as_ref() == letter.as_ref() => result[index] = Feedback::Green,
+                Some(expected) => *unmatched.entry(expected.as_ref()).or_default() += 1,
+                None => {}
+            }
+        }
+
+        for (index, letter) in guess.iter().enumerate() {
+            if result[index] == Feedback::Green {
+                continue;
+            }
+
+            if let Some(remaining) = unmatched.get_mut(letter.as_ref()).filter(|remaining| **remaining > 0) {
+                *remaining -= 1;
+                result[index] = Feedback::Yellow;
+            }
+        }
+
+        result
+    }
+}
+
+/// A word played in a Wordle style game and the feedback received for each of its letters
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Guess {
+    pub word: String,
+    pub feedback: Vec<Feedback>,
+}
+
+impl Guess {
+    pub fn new(word: impl AsRef<str>, feedback: Vec<Feedback>) -> Self {
+        Self { word: word.as_ref().to_owned(), feedback }
+    }
+}
+
+/// What the feedback of previous guesses tells us about the hidden word
+struct Constraints {
+    length: usize,
+    /// letters known to be in a position (greens)
+    fixed: Vec<Option<String>>,
+    /// letters known not to be in a position (yellows and greys)
+    forbidden: Vec<HashSet<String>>,
+    /// the least number of times a letter appears in the word
+    min_count: HashMap<String, usize>,
+    /// the most number of times a letter can appear in the word
+    max_count: HashMap<String, usize>,
+}
+
+impl Constraints {
+    fn new(dawg: &Dawg, length: usize, guesses: &[Guess]) -> Self {
+        let mut constraints = Self {
+            length,
+            fixed: vec![None; length],
+            forbidden: vec![HashSet::new(); length],
+            min_count: HashMap::new(),
+            max_count: HashMap::new(),
+        };
+
+        for guess in guesses {
+            let letters = dawg.tokenize(&guess.word);
+            let mut found: HashMap<&str, usize> = HashMap::new();
+            let mut absent: HashSet<&str> = HashSet::new();
+
+            for (index, (letter, feedback)) in letters.iter().zip(&guess.feedback).enumerate().take(length) {
+                match feedback {
+                    Feedback::Green => {
+                        constraints.fixed[index] = Some(letter.to_string());
+                        *found.entry(letter.as_str()).or_default() += 1;
+                    }
+                    Feedback::Yellow => {
+                        constraints.forbidden[index].insert(letter.to_string());
+                        *found.entry(letter.as_str()).or_default() += 1;
+                    }
+                    Feedback::Grey => {
+                        constraints.forbidden[index].insert(letter.to_string());
+                        absent.insert(letter.as_str());
+                    }
+                }
+            }
+
+            for (letter, count) in &found {
+                let min = constraints.min_count.entry(letter.to_string()).or_default();
+                *min = (*min).max(*count);
+            }
+
+            // a grey letter means the word has exactly as many copies of it as were coloured in this guess
+            for letter in absent {
+                let count = found.get(letter).copied().unwrap_or(0);
+                let max = constraints.max_count.entry(letter.to_string()).or_insert(count);
+                *max = (*max).min(count);
+            }
+        }
+
+        constraints
+    }
+
+    /// Whether `letter` can be placed at `index`, given the letters already used in the word
+    fn allows(&self, index: usize, letter: &str, used: &HashMap<String, usize>) -> bool {
+        if let Some(fixed) = &self.fixed[index] {
+            if fixed != letter {
+                return false;
+            }
+        }
+
+        if self.forbidden[index].contains(letter) {
+            return false;
+        }
+
+        let count = used.get(letter).copied().unwrap_or(0) + 1;
+        self.max_count.get(letter).map_or(true, |max| count <= *max)
+    }
+
+    /// The number of letters that still have to be placed to satisfy the minimum counts
+    fn missing(&self, used: &HashMap<String, usize>) -> usize {
+        self.min_count.iter()
+            .map(|(letter, min)| min.saturating_sub(used.get(letter).copied().unwrap_or(0)))
+            .sum()
+    }
+}
+
+impl Dawg {
+    fn filter_candidates(&self, node: &Node, constraints: &Constraints, word: &mut Vec<String>, used: &mut HashMap<String, usize>, result: &mut Vec<String>) {
+        let remaining = constraints.length - word.len();
+
+        #[cfg(not(feature = "threading"))]
+        let current = node.borrow();
+        #[cfg(feature = "threading")]
+        let current = node.lock().unwrap();
+
+        // no word of the right length is reachable from here
+        if !current.reaches_length(remaining) || constraints.missing(used) > remaining {
+            return;
+        }
+
+        if remaining == 0 {
+            result.push(word.concat());
+            return;
+        }
+
+        for (letter, child) in self.sorted_edges(&current) {
+            if !constraints.allows(word.len(), &letter, used) {
+                continue;
+            }
+
+            word.push(letter.to_owned());
+            *used.entry(letter.to_owned()).or_default() += 1;
+
+            self.filter_candidates(&child, constraints, word, used, result);
+
+            *used.get_mut(&letter).unwrap() -= 1;
+            word.pop();
+        }
+    }
+
+    /// Returns the feedback for `guess` when the hidden word is `answer` like [`Feedback::compute`],
+    /// with the words split into the letters of this dawg (see [`Dawg::tokenize`])
+    pub fn feedback(&self, guess: impl AsRef<str>, answer: impl AsRef<str>) -> Vec<Feedback> {
+        Feedback::compare(&self.tokenize(guess), &self.tokenize(answer))
+    }
+
+    /// Returns (in the order of the dawg) every word of `length` letters that is consistent with the feedback of all the previous `guesses`
+    /// The dawg is walked once, dropping branches as soon as a letter breaks a constraint
+    /// (a green elsewhere, a yellow/grey in this position, too many copies of a letter) or when no word of the right length is reachable
+    ///
+    /// ```rust
+    /// use dawg::{Dawg, Feedback, Guess};
+    ///
+    /// let mut words = vec!["CRANE", "CRATE", "GRATE", "TRACE", "SLATE"];
+    /// words.sort();
+    ///
+    /// let mut lexicon = Dawg::new();
+    /// for word in words {
+    ///     lexicon.insert(word.to_string());
+    /// }
+    /// lexicon.finish();
+    ///
+    /// use Feedback::*;
+    /// let guesses = vec![Guess::new("SLATE", vec![Grey, Grey, Green, Green, Green])];
+    ///
+    /// assert_eq!(lexicon.wordle_candidates(5, &guesses), vec!["CRATE", "GRATE"]);
+    /// ```
+    pub fn wordle_candidates(&self, length: usize, guesses: &[Guess]) -> Vec<String> {
+        let constraints = Constraints::new(self, length, guesses);
+        let mut result = vec![];
+
+        self.filter_candidates(&self.get_root(), &constraints, &mut vec![], &mut HashMap::new(), &mut result);
+        result
+    }
+
+    /// Ranks every word of `length` letters as the next guess, by the information (entropy in bits) its feedback is expected to reveal
+    /// about the words that are still possible given the previous `guesses`
+    /// The best guess comes first; on equal entropy, words that could still be the answer come first, then the order of the dawg
+    ///
+    /// Every possible guess is scored against every remaining candidate, so this gets expensive with large lexicons and no guesses yet
+    pub fn best_guesses(&self, length: usize, guesses: &[Guess]) -> Vec<(String, f64)> {
+        let candidates = self.wordle_candidates(length, guesses);
+        let possible = candidates.iter().collect::<HashSet<_>>();
+        let total = candidates.len() as f64;
+        let answers = candidates.iter().map(|answer| self.tokenize(answer)).collect::<Vec<_>>();
+
+        let mut ranked = self.wordle_candidates(length, &[]).into_iter().map(|guess| {
+            let letters = self.tokenize(&guess);
+            let mut outcomes: HashMap<Vec<Feedback>, usize> = HashMap::new();
+
+            for answer in &answers {
+                *outcomes.entry(Feedback::compare(&letters, answer)).or_default() += 1;
+            }
+
+            let entropy = outcomes.values().map(|count| {
+                let probability = *count as f64 / total;
+                -probability * probability.log2()
+            }).sum::<f64>();
+
+            (guess, entropy)
+        }).collect::<Vec<_>>();
+
+        // the sort is stable, so the words are left in the order of the dawg on a tie
+        ranked.sort_by(|a, b| {
+            b.1.total_cmp(&a.1)
+                .then_with(|| possible.contains(&b.0).cmp(&possible.contains(&a.0)))
+        });
+
+        ranked
+    }
+}
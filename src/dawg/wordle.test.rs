@@ -0,0 +1,128 @@
+#[cfg(test)]
+mod test_wordle {
+    use crate::dawg::collation::Alphabet;
+    use crate::dawg::dawg::Dawg;
+    use crate::dawg::symbol::Tiles;
+    use crate::dawg::wordle::{Feedback, Guess};
+    use Feedback::*;
+
+    fn setup_dawg() -> Dawg {
+        let mut words = vec![
+            "ABBEY", "CRANE", "CRATE", "GRATE", "TRACE", "SLATE", "STALE", "LEAST", "SPEED", "ERASE",
+            "EERIE", "CAT", "CRATES", "ÀYÒKÀ",
+        ];
+        words.sort();
+
+        let mut dawg = Dawg::new();
+        for word in words {
+            dawg.insert(word.to_string());
+        }
+        dawg.finish();
+        dawg
+    }
+
+    #[test]
+    fn should_compute_the_feedback_of_a_guess() {
+        assert_eq!(Feedback::compute("CRANE", "CRATE"), vec![Green, Green, Green, Grey, Green]);
+        assert_eq!(Feedback::compute("TRACE", "CRATE"), vec![Yellow, Green, Green, Yellow, Green]);
+        // only one of the "E"s can be coloured since the answer has a single one
+        assert_eq!(Feedback::compute("SPEED", "CRANE"), vec![Grey, Grey, Yellow, Grey, Grey]);
+        assert_eq!(Feedback::compute("EERIE", "ERASE"), vec![Green, Grey, Yellow, Grey, Green]);
+    }
+
+    #[test]
+    fn should_return_every_word_of_the_length_without_guesses() {
+        let dawg = setup_dawg();
+
+        assert_eq!(dawg.wordle_candidates(5, &[]).len(), 12);
+        assert_eq!(dawg.wordle_candidates(3, &[]), vec!["CAT"]);
+        assert_eq!(dawg.wordle_candidates(6, &[]), vec!["CRATES"]);
+        assert!(dawg.wordle_candidates(7, &[]).is_empty());
+    }
+
+    #[test]
+    fn should_filter_with_green_yellow_and_grey_letters() {
+        let dawg = setup_dawg();
+
+        let guesses = vec![Guess::new("SLATE", vec![Grey, Grey, Green, Green, Green])];
+        assert_eq!(dawg.wordle_candidates(5, &guesses), vec!["CRATE", "GRATE"]);
+
+        let guesses = vec![
+            Guess::new("SLATE", vec![Grey, Grey, Green, Green, Green]),
+            Guess::new("GRATE", vec![Grey, Green, Green, Green, Green]),
+        ];
+        assert_eq!(dawg.wordle_candidates(5, &guesses), vec!["CRATE"]);
+
+        let guesses = vec![Guess::new("STALE", vec![Grey, Yellow, Green, Grey, Green])];
+        assert_eq!(dawg.wordle_candidates(5, &guesses), vec!["CRATE", "GRATE", "TRACE"]);
+    }
+
+    #[test]
+    fn should_bound_letter_counts() {
+        let dawg = setup_dawg();
+
+        // a green and a grey "E": the word has exactly one "E"
+        let guesses = vec![Guess::new("SPEED", vec![Grey, Grey, Grey, Green, Grey])];
+        assert_eq!(dawg.wordle_candidates(5, &guesses), vec!["ABBEY"]);
+
+        // two coloured "E"s and a grey one: the word has exactly two
+        let guesses = vec![Guess::new("EERIE", vec![Green, Grey, Yellow, Grey, Green])];
+        assert_eq!(dawg.wordle_candidates(5, &guesses), vec!["ERASE"]);
+    }
+
+    #[test]
+    fn should_support_accented_letters() {
+        let dawg = setup_dawg();
+
+        let guesses = vec![Guess::new("ÀYÒKÀ", vec![Green, Green, Green, Green, Green])];
+        assert_eq!(dawg.wordle_candidates(5, &guesses), vec!["ÀYÒKÀ"]);
+    }
+
+    #[test]
+    fn should_rank_guesses_by_expected_information() {
+        let dawg = setup_dawg();
+        let guesses = vec![Guess::new("SLATE", vec![Grey, Grey, Green, Green, Green])];
+
+        // "CRATE" and "GRATE" are left, every guess that tells them apart is worth one bit
+        let ranked = dawg.best_guesses(5, &guesses);
+        assert_eq!(ranked.len(), 12);
+        assert_eq!(ranked[0], ("CRATE".to_string(), 1.0));
+        assert_eq!(ranked[1], ("GRATE".to_string(), 1.0));
+        assert_eq!(ranked[2], ("CRANE".to_string(), 1.0));
+        assert_eq!(ranked.last().unwrap().1, 0.0);
+    }
+
+    #[test]
+    fn should_count_multi_letter_tiles_as_one_letter() {
+        let mut dawg = Dawg::with_tokenizer(Tiles::new(["CH", "LL"]));
+        let mut words = ["LLAMA", "LUNA", "LAMA", "CHAL"].map(String::from);
+        dawg.sort_words(&mut words);
+        for word in words {
+            dawg.insert(word);
+        }
+        dawg.finish();
+
+        assert_eq!(dawg.wordle_candidates(4, &[]), vec!["LAMA", "LUNA", "LLAMA"]);
+        assert_eq!(dawg.wordle_candidates(3, &[]), vec!["CHAL"]);
+        assert_eq!(dawg.feedback("LAMA", "LLAMA"), vec![Grey, Green, Green, Green]);
+
+        let guesses = vec![Guess::new("LLAMA", vec![Green, Green, Green, Green])];
+        assert_eq!(dawg.wordle_candidates(4, &guesses), vec!["LLAMA"]);
+    }
+
+    #[test]
+    fn should_return_the_words_in_the_order_of_the_collator() {
+        let mut dawg = Dawg::with_collator(Alphabet::spanish());
+        let mut words = ["MAZO", "MAÑO", "MANO"].map(String::from);
+        dawg.sort_words(&mut words);
+        for word in words {
+            dawg.insert(word);
+        }
+        dawg.finish();
+
+        assert_eq!(dawg.wordle_candidates(4, &[]), vec!["MANO", "MAÑO", "MAZO"]);
+        // every guess tells the three words apart, so the ranking keeps the order of the dawg
+        let ranked = dawg.best_guesses(4, &[]).into_iter().map(|(word, _)| word).collect::<Vec<_>>();
+        assert_eq!(ranked, vec!["MANO", "MAÑO", "MAZO"]);
+    }
+}
@@ -0,0 +1,110 @@
+use std::sync::Arc;
+
+use crate::dawg::collation::Collator;
+use crate::dawg::dawg::Dawg;
+use crate::dawg::symbol::Symbol;
+use crate::node::node::Node;
+
+#[cfg(test)]
+#[path = "./words.test.rs"]
+mod words_test;
+
+/// A lazy iterator over the sequences of a dawg (or the sequences under a prefix) in sorted order
+/// Returned by [`Dawg::sequences`] and [`Dawg::sequences_with_prefix`]
+pub struct Sequences<S: Symbol = String> {
+    /// the nodes left to visit, and the sequence formed on the way to them (the next one to visit is last)
+    stack: Vec<(Vec<S>, Node<S>)>,
+    /// the collator of the dawg (see [`Dawg::set_collator`])
+    collator: Option<Arc<dyn Collator<S> + Send + Sync>>,
+}
+
+impl<S: Symbol> Sequences<S> {
+    pub(crate) fn new(dawg: &Dawg<S>, prefix: Vec<S>, node: Option<Node<S>>) -> Self {
+        Self {
+            stack: node.map(|node| vec![(prefix, node)]).unwrap_or_default(),
+            collator: dawg.collator.clone(),
+        }
+    }
+}
+
+impl<S: Symbol> Iterator for Sequences<S> {
+    type Item = Vec<S>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((sequence, node)) = self.stack.pop() {
+            #[cfg(not(feature = "threading"))]
+            let current = node.borrow();
+            #[cfg(feature = "threading")]
+            let current = node.lock().unwrap();
+
+            let mut edges = current.sorted_edges();
+            if let Some(collator) = &self.collator {
+                edges.sort_by(|a, b| collator.compare(&a.0, &b.0));
+            }
+
+            // children are pushed in reverse, so that the smallest one is visited first
+            for (symbol, child) in edges.into_iter().rev() {
+                let mut next = sequence.to_owned();
+                next.push(symbol);
+                self.stack.push((next, child));
+            }
+
+            if current.terminal {
+                return Some(sequence);
+            }
+        }
+
+        None
+    }
+}
+
+/// A lazy iterator over the words of a dawg (or the words under a prefix) in alphabetical order
+/// Returned by [`Dawg::words`] and [`Dawg::words_with_prefix`]
+pub struct Words(Sequences<String>);
+
+impl Iterator for Words {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|letters| letters.concat())
+    }
+}
+
+impl<S: Symbol> Dawg<S> {
+    /// Returns an iterator over all the sequences in the dawg, in sorted order
+    pub fn sequences(&self) -> Sequences<S> {
+        Sequences::new(self, vec![], Some(self.get_root()))
+    }
+
+    /// Returns an iterator over the sequences starting with `prefix` (the prefix itself included if it was inserted), in sorted order
+    pub fn sequences_with_prefix(&self, prefix: &[S]) -> Sequences<S> {
+        Sequences::new(self, prefix.to_vec(), self.lookup_sequence(prefix))
+    }
+}
+
+impl Dawg {
+    /// Returns an iterator over all the words in the lexicon, in alphabetical order
+    ///
+    /// ```rust
+    /// use dawg::Dawg;
+    ///
+    /// let mut lexicon = Dawg::new();
+    /// for word in ["CAR", "CARE", "CAT", "DOG"] {
+    ///     lexicon.insert(word.to_string());
+    /// }
+    /// lexicon.finish();
+    ///
+    /// assert_eq!(lexicon.words().collect::<Vec<_>>(), vec!["CAR", "CARE", "CAT", "DOG"]);
+    /// assert_eq!(lexicon.words_with_prefix("CAR").collect::<Vec<_>>(), vec!["CAR", "CARE"]);
+    /// ```
+    pub fn words(&self) -> Words {
+        Words(self.sequences())
+    }
+
+    /// Returns an iterator over the words starting with `prefix` (the prefix itself included if it is a word), in alphabetical order
+    pub fn words_with_prefix(&self, prefix: impl AsRef<str>) -> Words {
+        let node = self.lookup(&prefix, true);
+        // the words start with the prefix as it is stored, not as it was given
+        Words(Sequences::new(self, vec![self.tokenize(prefix).concat()], node))
+    }
+}
@@ -0,0 +1,54 @@
+#[cfg(test)]
+mod test_words {
+    use crate::dawg::dawg::Dawg;
+    use crate::dawg::normalize::Normalization;
+
+    fn setup_dawg() -> Dawg {
+        let mut words = vec!["BAM", "BAT", "BATH", "BATHE", "CAR", "CARS", "CATH", "a", "alpa", "ÒYA", "AYÒ"];
+        words.sort();
+
+        let mut dawg = Dawg::new();
+        for word in words {
+            dawg.insert(word.to_string());
+        }
+        dawg.finish();
+        dawg
+    }
+
+    #[test]
+    fn should_iterate_over_all_words_in_order() {
+        let dawg = setup_dawg();
+
+        let received = dawg.words().collect::<Vec<_>>();
+        let expected = vec!["AYÒ", "BAM", "BAT", "BATH", "BATHE", "CAR", "CARS", "CATH", "a", "alpa", "ÒYA"];
+
+        assert_eq!(received, expected);
+    }
+
+    #[test]
+    fn should_iterate_over_the_words_with_a_prefix() {
+        let dawg = setup_dawg();
+
+        assert_eq!(dawg.words_with_prefix("BAT").collect::<Vec<_>>(), vec!["BAT", "BATH", "BATHE"]);
+        assert_eq!(dawg.words_with_prefix("CA").collect::<Vec<_>>(), vec!["CAR", "CARS", "CATH"]);
+        assert_eq!(dawg.words_with_prefix("").count(), 11);
+        assert_eq!(dawg.words_with_prefix("DOG").count(), 0);
+    }
+
+    #[test]
+    fn should_start_the_words_with_the_normalized_prefix() {
+        let mut dawg = Dawg::with_normalization(Normalization::Nfd);
+        dawg.insert("O\u{300}YA\u{300}".to_string());
+        dawg.finish();
+
+        assert_eq!(dawg.words_with_prefix("\u{D2}Y").collect::<Vec<_>>(), vec!["O\u{300}YA\u{300}"]);
+    }
+
+    #[test]
+    fn should_be_empty_for_an_empty_dawg() {
+        let mut dawg = Dawg::new();
+        dawg.finish();
+
+        assert_eq!(dawg.words().count(), 0);
+    }
+}
@@ -11,6 +11,7 @@
 //! features = ["threading" ]
 //! ```
 //! [threading] - Support Send + Sync
+//! [gzip] - Read gzipped word lists with `Dawg::from_path`
 //! 
 //! ```Rust
 //! use dawg::Dawg;
@@ -37,5 +38,28 @@
 mod dawg;
 mod node;
 
+pub use crate::dawg::anagram::{Anagrams, BLANK};
+pub use crate::dawg::automaton::SuffixAutomaton;
+pub use crate::dawg::case::CaseFolding;
+pub use crate::dawg::collation::{Alphabet, Collator};
+pub use crate::dawg::compact::{CompactDawg, CompactWords};
+pub use crate::dawg::compound::{CompoundPart, CompoundRules};
+pub use crate::dawg::cursor::DawgCursor;
 pub use crate::dawg::dawg::Dawg;
+pub use crate::dawg::gaddag::{Gaddag, Placement};
+pub use crate::dawg::grid::GridRules;
+pub use crate::dawg::hunspell::{Hunspell, HunspellWord};
+pub use crate::dawg::infix::InfixIndex;
+pub use crate::dawg::ladder::Distance;
+pub use crate::dawg::normalize::Normalization;
+pub use crate::dawg::prefix::{PrefixMatch, Prefixes};
+pub use crate::dawg::reader::{Progress, ReadError};
+pub use crate::dawg::scan::{MatchKind, ScanMatch, ScanMatches, ScanOptions, Scanner};
+pub use crate::dawg::segment::{Scoring, Segment, Segmenter};
+pub use crate::dawg::shape::UNKNOWN_LETTER;
+pub use crate::dawg::spell::{CaseHandling, Misspelling, SpellOptions};
+pub use crate::dawg::suffix::SuffixDawg;
+pub use crate::dawg::wordle::{Feedback, Guess};
+pub use crate::dawg::symbol::{Bytes, Chars, Graphemes, Symbol, Tiles, Tokenizer};
+pub use crate::dawg::words::{Sequences, Words};
 pub use crate::node::node::Node;
@@ -9,31 +9,50 @@ use std::fmt::Display;
 
 use serde::{Deserialize, Serialize};
 
+use crate::dawg::symbol::Symbol;
+
 #[cfg(test)]
 #[path = "./node.test.rs"]
 mod node_test;
 
 #[cfg(not(feature = "threading"))]
-pub type Node = Rc<RefCell<DawgNode>>;
+pub type Node<S = String> = Rc<RefCell<DawgNode<S>>>;
 #[cfg(feature = "threading")]
-pub type Node = Arc<Mutex<DawgNode>>;
+pub type Node<S = String> = Arc<Mutex<DawgNode<S>>>;
 
 
 
 /// `Node`: Represents a letter in the DAWG,
 #[derive(Debug, Serialize, Deserialize)]
-pub struct DawgNode {
+#[serde(bound(deserialize = "S: Symbol + Deserialize<'de>"))]
+pub struct DawgNode<S = String> {
     pub(crate) id: usize,
     /// specifies whether this node is the end of a valid `WORD` in your dictionary
     /// TRUE: Yes, it is the end of a valid word
     /// FALSE: No, it is not the end of a valid word
     pub terminal: bool,
     /// Letters(nodes) that extend from this letter (node)
-    pub(crate) edges: HashMap<String, Node>,
+    pub(crate) edges: HashMap<S, Node<S>>,
+    /// The letters of `edges` in the order of the dawg (see [`Collator`](crate::Collator)), set once the dawg is finished
+    /// so that traversals don't sort the edges of every node they visit
+    #[serde(default)]
+    pub(crate) order: Vec<S>,
     /// Specifies the total number of word terminals resulting from this node,
     /// this word terminals (letters that end a word) can be direct children, grand-children, 
     /// or even great-grand-children of this node
     pub count: usize,
+    /// The highest score of the words going through this node (including the one ending on it), whatever the path they reach it by:
+    /// nodes are shared by words with different scores, so this is an upper bound for the words of any one path (see [`Dawg::top_k`](crate::Dawg::top_k))
+    #[serde(default)]
+    pub max_score: u64,
+    /// The lengths of the words reachable from this node, bit `n` is set when a word terminal is `n` letters below this node
+    /// (bit 63 stands for 63 letters or more). Nodes serialized without it can reach any length
+    #[serde(default = "any_length")]
+    pub(crate) lengths: u64,
+}
+
+fn any_length() -> u64 {
+    u64::MAX
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -46,7 +65,12 @@ impl DawgWrapper {
         Self { next_id: 0 }
     }
 
-    pub fn create(&mut self) -> Node {
+    /// Returns the number of nodes created so far
+    pub(crate) fn created(&self) -> usize {
+        self.next_id
+    }
+
+    pub fn create<S: Symbol>(&mut self) -> Node<S> {
         let node = DawgNode::new(self.next_id);
         self.next_id += 1;
 
@@ -57,56 +81,87 @@ impl DawgWrapper {
     }
 }
 
-impl DawgNode {
+impl<S: Symbol> DawgNode<S> {
     /// Creates a new node (DawgNode)
     pub fn new(id: usize) -> Self {
         Self {
             id,
             terminal: false,
             edges: HashMap::new(),
+            order: vec![],
             count: 0,
+            max_score: 0,
+            lengths: 0,
         }
     }
 
 
     /// Returns the total number of word terminals that result(are extended) from this node
     /// this can be chidlren/grand-children/great-grand-children e.t.c
+    /// The lengths of those terminals are recorded on `lengths` along the way
     pub(crate) fn num_reachable(&mut self) -> usize {
         if self.count != 0 {
             return self.count;
         }
 
         let mut count = 0;
+        let mut lengths = 0;
 
         if self.terminal {
             count += 1;
+            lengths = 1;
         }
 
-        for (_, value) in &mut self.edges {        
+        for value in self.edges.values_mut() {
             #[cfg(not(feature = "threading"))]
-            {count += value.borrow_mut().num_reachable();}
+            {
+                let mut handle = value.borrow_mut();
+                count += handle.num_reachable();
+                lengths |= (handle.lengths << 1) | (handle.lengths & 1 << 63);
+            }
 
             #[cfg(feature = "threading")]
             if let Ok(mut handle) = value.lock() {
                 count += handle.num_reachable();
+                lengths |= (handle.lengths << 1) | (handle.lengths & 1 << 63);
             }
         }
 
         self.count = count;
-        return count;
+        self.lengths = lengths;
+        count
     }
 
-    pub fn edge_keys(&self) -> Vec<&String> {
+    /// Whether a word terminal can be found exactly `length` letters below this node
+    /// (only available after the dawg has been closed with `finish`)
+    pub(crate) fn reaches_length(&self, length: usize) -> bool {
+        self.lengths & 1 << length.min(63) != 0
+    }
+
+    pub fn edge_keys(&self) -> Vec<&S> {
         let keys = self.edges.keys().collect::<Vec<_>>();
         keys
     }
 
-    pub fn edges(&self) -> &HashMap<String, Node> {
+    pub fn edges(&self) -> &HashMap<S, Node<S>> {
         &self.edges
     }
+
+    /// Returns the edges of this node ordered by their letters, for traversals that need a stable order
+    pub(crate) fn sorted_edges(&self) -> Vec<(S, Node<S>)> {
+        let mut edges = self.edges.iter().map(|(letter, node)| {
+            #[cfg(not(feature = "threading"))]
+            return (letter.to_owned(), Rc::clone(node));
+            #[cfg(feature = "threading")]
+            return (letter.to_owned(), Arc::clone(node));
+        }).collect::<Vec<_>>();
+
+        edges.sort_by(|a, b| a.0.cmp(&b.0));
+        edges
+    }
 }
 
-impl Display for DawgNode {
+impl<S: Symbol> Display for DawgNode<S> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut arr = vec![];
 
@@ -116,7 +171,11 @@ impl Display for DawgNode {
             arr.push("0".to_string());
         }
 
-        for (key, value) in &self.edges {
+        // edges are sorted so that equivalent nodes always get the same signature (HashMap iteration order is not stable)
+        let mut edges = self.edges.iter().collect::<Vec<_>>();
+        edges.sort_by(|a, b| a.0.cmp(b.0));
+
+        for (key, value) in edges {
             #[cfg(not(feature = "threading"))]
             let id = value.try_borrow().unwrap().id.to_string();
 
@@ -124,7 +183,8 @@ impl Display for DawgNode {
             let id = value.lock().unwrap().id.to_string();
 
             arr.push(id);
-            arr.push(key.to_string())
+            // the debug format of a symbol is unambiguous (e.g. strings are quoted), unlike its display format
+            arr.push(format!("{:?}", key))
         }
 
         let name = arr.join("_");
@@ -133,21 +193,21 @@ impl Display for DawgNode {
     }
 }
 
-impl Eq for DawgNode {}
+impl<S: Symbol> Eq for DawgNode<S> {}
 
-impl Ord for DawgNode {
+impl<S: Symbol> Ord for DawgNode<S> {
     fn cmp(&self, other: &Self) -> Ordering {
         self.to_string().cmp(&other.to_string())
     }
 }
 
-impl PartialOrd for DawgNode {
+impl<S: Symbol> PartialOrd for DawgNode<S> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(&other))
+        Some(self.cmp(other))
     }
 }
 
-impl PartialEq for DawgNode {
+impl<S: Symbol> PartialEq for DawgNode<S> {
     fn eq(&self, other: &Self) -> bool {
         self.to_string() == other.to_string()
     }
@@ -20,7 +20,7 @@ mod test_dawg_node {
 
         assert_eq!(dawg_wrapper.next_id, 0);
 
-        let dawg_node = dawg_wrapper.create();
+        let dawg_node = dawg_wrapper.create::<String>();
         
         
         
@@ -39,7 +39,7 @@ mod test_dawg_node {
         assert_eq!(dawg_wrapper.next_id, 1);        
 
         
-        let new_dawg_node = dawg_wrapper.create();
+        let new_dawg_node = dawg_wrapper.create::<String>();
         {
             #[cfg(feature = "threading")]
             let mut node_one = new_dawg_node.lock().unwrap();
@@ -73,11 +73,11 @@ mod test_dawg_node {
         #[cfg(feature = "threading")]
         let mut nodes: Vec<Arc<Mutex<DawgNode>>>  = Vec::with_capacity(8);
 
-        let dawg_node = dawg_wrapper.create();
+        let dawg_node = dawg_wrapper.create::<String>();
         nodes.push(dawg_node);
 
         for id in 'a'..='f' {
-            let dawg_node = dawg_wrapper.create();
+            let dawg_node = dawg_wrapper.create::<String>();
 
             #[cfg(feature = "threading")]
             let mut ref_node = dawg_node.lock().unwrap();